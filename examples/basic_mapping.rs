@@ -73,6 +73,7 @@ mod types {
         }
     }
 
+    #[allow(clippy::derivable_impls)]
     impl Default for Rank {
         fn default() -> Self {
             Rank::Junior
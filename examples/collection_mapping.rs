@@ -51,6 +51,10 @@ mod types {
     }
 
     // --------- Helper transforms (owned) ---------
+    // These are the same helpers `simple_dto_mapper::helpers` ships. They are copied here
+    // because this example belongs to the derive crate, and that crate cannot depend on
+    // `simple_dto_mapper`, which itself depends on the derive. With `simple_dto_mapper` as
+    // your dependency, use `simple_dto_mapper::helpers::vec_into` and friends instead.
     /// Vec<T> → Vec<U> using Into
     pub fn vec_into<T, U>(v: Vec<T>) -> Vec<U>
     where
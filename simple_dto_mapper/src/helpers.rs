@@ -0,0 +1,40 @@
+//! Generic transforms for `#[dto(transform_fn = ...)]`.
+//!
+//! Each one converts the elements of a container with `Into`, for the cases where the
+//! derive's own `into` / `collect` keys do not fit, e.g. inside a `with_fields` transform or a
+//! hand-written `From` impl.
+
+use alloc::vec::Vec;
+
+/// `Vec<T>` → `Vec<U>`, converting every element with `Into`.
+pub fn vec_into<T, U>(v: Vec<T>) -> Vec<U>
+where
+    U: From<T>,
+{
+    v.into_iter().map(Into::into).collect()
+}
+
+/// `Option<T>` → `Option<U>`, converting the value with `Into`.
+pub fn opt_into<T, U>(o: Option<T>) -> Option<U>
+where
+    U: From<T>,
+{
+    o.map(Into::into)
+}
+
+/// Any map → any map, converting every value with `Into` and keeping the keys, so
+/// `HashMap<K, V>` becomes `HashMap<K, W>` or even a `BTreeMap<K, W>`.
+pub fn map_into<K, V, W, M, N>(m: M) -> N
+where
+    M: IntoIterator<Item = (K, V)>,
+    N: FromIterator<(K, W)>,
+    W: From<V>,
+{
+    m.into_iter().map(|(k, v)| (k, v.into())).collect()
+}
+
+/// `Option<NaiveDateTime>` → `Option<DateTime<Utc>>`, reading the naive value as UTC.
+#[cfg(feature = "chrono")]
+pub fn opt_naive_to_utc(o: Option<chrono::NaiveDateTime>) -> Option<chrono::DateTime<chrono::Utc>> {
+    o.map(|ndt| chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(ndt, chrono::Utc))
+}
@@ -0,0 +1,51 @@
+//! # simple_dto_mapper
+//!
+//! The derive macros of [`simple_dto_mapper_derive`] together with the transform helpers most
+//! mappings end up defining by hand. A proc-macro crate can only export macros, so the helpers
+//! live here:
+//!
+//! ```rust
+//! use simple_dto_mapper::helpers::{opt_into, vec_into};
+//! use simple_dto_mapper::DtoFrom;
+//!
+//! pub struct Tag(pub String);
+//! pub struct Label(pub String);
+//!
+//! impl From<Tag> for Label {
+//!     fn from(t: Tag) -> Self {
+//!         Label(t.0)
+//!     }
+//! }
+//!
+//! pub struct Post {
+//!     pub tags: Vec<Tag>,
+//!     pub pinned: Option<Tag>,
+//! }
+//!
+//! #[derive(DtoFrom)]
+//! #[dto(from = Post)]
+//! pub struct PostDto {
+//!     #[dto(transform_fn = vec_into)]
+//!     pub tags: Vec<Label>,
+//!     #[dto(transform_fn = opt_into)]
+//!     pub pinned: Option<Label>,
+//! }
+//!
+//! let dto: PostDto = Post {
+//!     tags: vec![Tag("rust".into())],
+//!     pinned: None,
+//! }
+//! .into();
+//! assert_eq!(dto.tags[0].0, "rust");
+//! ```
+//!
+//! The helpers need only `alloc`, so the crate is `#![no_std]`. The `chrono` feature adds
+//! [`helpers::opt_naive_to_utc`] and enables the derive's `naive_to_utc` / `utc_to_naive` keys.
+
+#![no_std]
+
+extern crate alloc;
+
+pub use simple_dto_mapper_derive::{DtoFrom, DtoInto, DtoTryFrom};
+
+pub mod helpers;
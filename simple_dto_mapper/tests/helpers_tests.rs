@@ -0,0 +1,80 @@
+//! Integration test for the helper transforms used through `transform_fn`.
+//!
+//! Covers:
+//! - `vec_into`, `opt_into`, and `map_into` with inferred type parameters
+//! - `opt_naive_to_utc` under the `chrono` feature
+//! - The derives re-exported from `simple_dto_mapper`
+
+use simple_dto_mapper::helpers::{map_into, opt_into, vec_into};
+use simple_dto_mapper::DtoFrom;
+use std::collections::{BTreeMap, HashMap};
+
+mod types {
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SourceTag(pub String);
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DtoTag(pub String);
+
+    impl From<SourceTag> for DtoTag {
+        fn from(t: SourceTag) -> Self {
+            DtoTag(t.0)
+        }
+    }
+
+    pub struct Article {
+        pub tags: Vec<SourceTag>,
+        pub lead: Option<SourceTag>,
+        pub scores: HashMap<String, u8>,
+    }
+}
+
+use types::*;
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = types::Article)]
+pub struct ArticleDto {
+    #[dto(transform_fn = vec_into)]
+    pub tags: Vec<DtoTag>,
+
+    #[dto(transform_fn = opt_into)]
+    pub lead: Option<DtoTag>,
+
+    // a `HashMap` source collected into a `BTreeMap` with widened values
+    #[dto(transform_fn = map_into)]
+    pub scores: BTreeMap<String, u32>,
+}
+
+#[test]
+fn test_helpers_convert_containers() {
+    let dto: ArticleDto = Article {
+        tags: vec![SourceTag("a".into()), SourceTag("b".into())],
+        lead: Some(SourceTag("lead".into())),
+        scores: HashMap::from([("x".to_string(), 7u8)]),
+    }
+    .into();
+
+    assert_eq!(dto.tags, [DtoTag("a".into()), DtoTag("b".into())]);
+    assert_eq!(dto.lead, Some(DtoTag("lead".into())));
+    assert_eq!(dto.scores.get("x"), Some(&7u32));
+
+    let none: Option<DtoTag> = opt_into(None::<SourceTag>);
+    assert_eq!(none, None);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_opt_naive_to_utc() {
+    use chrono::NaiveDate;
+    use simple_dto_mapper::helpers::opt_naive_to_utc;
+
+    let naive = NaiveDate::from_ymd_opt(2024, 5, 1)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+    let utc = opt_naive_to_utc(Some(naive)).unwrap();
+    assert_eq!(utc.naive_utc(), naive);
+    assert_eq!(opt_naive_to_utc(None), None);
+}
@@ -2,23 +2,300 @@
 //! ## Detailed Documentation
 //!
 //! The `DtoFrom` derive macro generates a `From<Source>` implementation for the target struct.
+//! Like every generated impl, it is marked `#[automatically_derived]` and allows clippy's `all` and
+//! `pedantic` groups, since the expanded code is not the user's to fix.
 //! It supports the following attributes on structs and fields:
 //!
 //! ### Struct-level Attribute (required)
 //! - `#[dto(from = Type)]`
-//!   - Specifies the source type `Type` (a Rust `Path`) from which to map.
+//!   - Specifies the source type `Type` from which to map.
+//!   - A reference such as `&'a Source` behaves like `by_ref` with that lifetime; lifetimes the DTO
+//!     does not declare are added to the impl. Wrappers such as `Arc<Source>` are accepted too, with
+//!     fields read through auto-deref (use `clone` or transforms, since nothing can be moved out).
 //!   - Must appear on the same item as `#[derive(DtoFrom)]`.
+//!   - May be repeated (`#[dto(from = A, from = B)]` or separate attributes) to generate one
+//!     `From` impl per source; every field must then resolve against every source.
+//!     Listing the same type twice (`Page<u8>` and `Page::<u8>` count as one) is an error that
+//!     names it.
+//!
+//! ### Struct-level Attribute (optional)
+//! - `#[dto(by_ref)]`
+//!   - Generates `impl From<&Source> for Target` instead, leaving the source usable afterwards.
+//!   - Direct fields become `source.field.clone()` and `into` fields `source.field.clone().into()`,
+//!     so **directly-mapped and converted field types must implement `Clone`**.
+//!   - Reference fields borrow instead: a direct `name: &'a str` becomes `&source.name`. A DTO with
+//!     one lifetime gets `impl<'a> From<&'a Source> for Dto<'a>`, so a view like
+//!     `struct UserView<'a> { name: &'a str, tags: &'a [String] }` maps without allocating. With
+//!     several lifetimes, name the one to borrow under in `from = &'a Source`.
+//!   - `transform_fn` receives a reference: the signature becomes `Fn(&SourceFieldType) -> FieldType`.
+//!   - Implied by a shared source, `#[dto(from = Arc<Source>)]` (or `Rc<Source>`): the generated
+//!     `From<Arc<Source>>` reads through the pointer and clones each field. `DtoInto` and
+//!     `both_refs` are rejected for shared sources.
+//! - `#[dto(both_refs)]`
+//!   - Generates both `impl From<Source>` (moving) and `impl From<&Source>` (cloning, as `by_ref`).
+//!   - Path transforms (`transform_fn`, `try_transform_fn`, `with`) are rejected, since one function
+//!     cannot take both `T` and `&T`; `transform_expr` closures and `from_source` transforms work.
+//! - `#[dto(bound = "T: Clone, U: Default")]`
+//!   - Adds `where` predicates to the generated impl(s), for bounds only the field conversions need.
+//!   - `#[dto(where = "T: Serialize + Clone")]` is the same key; a leading `where` in the string is
+//!     allowed. Both may repeat, and every predicate is kept.
+//! - `#[dto(generics_from_source)]`
+//!   - Declares the type parameters named in `from` on the generated impl, so a DTO that does not
+//!     carry them need not redeclare them: `from = Page<T>` on a non-generic DTO generates
+//!     `impl<T> From<Page<T>> for Dto`. Combine with `bound = "..."` for the bounds the source needs.
+//!   - Every bare identifier argument counts, so spell concrete arguments as paths
+//!     (`std::string::String`). A parameter also declared on the DTO is an error; `DtoInto` rejects
+//!     the key, since the impl could not constrain the parameter.
+//! - `#[dto(rename_all = "camelCase")]`
+//!   - Derives each source field name from the DTO field name in the given case: `user_id` reads
+//!     `source.userId`. Supports `lowercase`, `UPPERCASE`, `snake_case`, `SCREAMING_SNAKE_CASE`,
+//!     `camelCase`, and `PascalCase`.
+//!   - A field-level `rename` overrides it; tuple positions are unaffected.
+//! - `#[dto(strip_prefix = "user_")]`
+//!   - The source repeats a prefix the DTO drops: an `id` field reads `source.user_id`.
+//!   - Joined before `rename_all` applies, so with `rename_all = "camelCase"` the same `id` reads
+//!     `source.userId`. A field-level `rename` is taken as written, without the prefix.
+//! - `#[dto(collection_helper)]`
+//!   - Also emits `Target::from_vec(Vec<Source>) -> Vec<Target>` on top of the `From` impl
+//!     (`try_from_vec`, returning the first error, for `DtoTryFrom`).
+//!   - And `Target::map_all(iter)`, which takes any `IntoIterator` of sources (an array, a `Vec`,
+//!     an iterator chain) and collects a `Vec<Target>`; `try_map_all` for `DtoTryFrom`.
+//! - `#[dto(debug)]`
+//!   - Prints the generated impl(s) to stderr while compiling, to check how `rename`s and
+//!     transforms are wired without reaching for `cargo expand`. Remove it when done; the
+//!     expansion itself is unchanged.
+//! - `#[dto(cfg_feature = "serde")]`
+//!   - Puts `#[cfg(feature = "serde")]` on everything the derive generates, so the mapping exists
+//!     only when the using crate enables that feature; the struct itself is always compiled.
+//! - `#[dto(inherent)]`
+//!   - Also emits `Target::map_from(source)`, delegating to the `From` impl, for call sites where
+//!     `Target::map_from(user)` reads better than `user.into()` or inference is ambiguous.
+//!     `DtoTryFrom` (and `fallible`) emits `Target::try_map_from(source)` instead.
+//! - `#[dto(const_fn)]`
+//!   - Also emits `pub const fn Target::from_const(source: Source) -> Self`, so the DTO can be built
+//!     in `const` items for embedded / `no_std`-style tables. Every field must be `const`-compatible:
+//!     a direct move, an `as` cast, or a skip with `default = expr` / `with_default_fn` (skipped
+//!     `Option`s become `None`). Transforms, `into` and the other calling keys are rejected, as are a
+//!     bare `skip` (it calls `Default::default()`), by-ref and multiple sources, and `fallible`.
+//!   - The compiler still checks the result: defaults must be `const`, and the source must not need
+//!     dropping (e.g. all `Copy` fields, or every non-`Copy` one moved into the DTO).
+//! - `#[dto(no_inline)]`
+//!   - The generated `from` / `try_from` is marked `#[inline]` by default; this leaves it off for
+//!     code-size-sensitive builds.
+//! - `#[dto(auto_nested)]`
+//!   - Maps every direct field through `Into::into(source.field)`, so a field whose type is itself a
+//!     DTO of the source field's type converts without `#[dto(into)]`. Same-typed fields still
+//!     compile through the reflexive `From<T> for T`; `DtoInto` converts back with `Into` as well.
+//! - `#[dto(exhaustive)]`
+//!   - Requires every field of the source struct to be read by some DTO field, so a source field
+//!     added later (or forgotten) fails to compile with "pattern does not mention field `x`".
+//!   - Implemented as a non-moving `let Source { a: _, b: _ } = source;` in the generated function.
+//!     Direct, `rename`d, `index`ed, and `source_path` fields (by their first segment) count;
+//!     getters and `from_source` transforms read nothing the macro can see.
+//! - `#[dto(post_build = path::to::hook)]`
+//!   - Calls `hook(&mut target, &source)` after every field is assigned, for cross-field fix-ups
+//!     the field attributes cannot express. The hook is `FnOnce(&mut Target, &Source)`.
+//!   - In owned mode the source must still be whole at that point, so moved (non-`Copy`) fields
+//!     need `clone`; with `by_ref` nothing is moved and the hook always compiles.
+//! - `#[dto(debug_assert_invariant = "self.start <= self.end")]`
+//!   - Checks a cross-field invariant on the constructed target with `debug_assert!` before it is
+//!     returned (after `post_build`, if any); `self` names the target. Compiles out in release builds.
+//!   - The panic message names the DTO and the expression. Not available with `const_fn`.
+//!
+//! ### Generic Structs
+//!
+//! The DTO's generic parameters and `where` clause are copied onto the generated impl, so a generic
+//! source is written with the DTO's own parameters: `#[dto(from = Page<T>)]` on `struct PageDto<T>`
+//! generates `impl<T> From<Page<T>> for PageDto<T>`. The turbofish spelling `Page::<T>` and type
+//! aliases such as `type UserPage = Page<User>;` are accepted too.
+//!
+//! ### Conditional Fields
+//!
+//! A field's `#[cfg(...)]` attributes are repeated on its initializer (and on any statement the
+//! mapping emits for it), so feature-gated DTO fields are mapped only when they are compiled in.
 //!
 //! ### Field-level Attributes
+//! - `#[dto(direct)]`
+//!   - Maps the field from the source field of the same name and type, which is also what an
+//!     unannotated field does; it says so explicitly, as the struct-level `only_annotated` requires.
+//!   - Conflicts with `skip`/`default`, with keys naming another source field (`rename`,
+//!     `source_path`, `index`, `from_map`, `getter`), and with every conversion.
 //! - `#[dto(rename = "orig_name")]`
 //!   - Maps the struct field to a differently named source field (by name).
+//!   - Only one `rename` per field: the macro cannot see which source fields exist, so there is no
+//!     fallback chain. To read a deprecated name as well, choose in a `transform_fn = path, from_source`.
+//!   - Keywords are read as raw identifiers: `rename = "type"` reads `source.r#type`.
+//! - `#[dto(source_path = "address.city")]`
+//!   - Reads a nested source field: the access expression becomes `source.address.city`.
+//!   - Numeric segments are tuple indices, so `"pair.0.name"` reads `source.pair.0.name` through a
+//!     tuple (or tuple-struct) field. Empty segments (`"address."`) and leading zeros are rejected.
+//!   - Conflicts with `rename`; composes with `transform_fn`, `into`, and the other conversions.
+//! - `#[dto(index = 0)]`
+//!   - Reads a positional field of a tuple-struct source, `source.0`, into a named DTO field.
+//!   - Conflicts with `rename`, `source_path`, and `getter`; `DtoInto` writes the value back to `0`.
+//! - `#[dto(from_map = "attributes", key = "email")]`
+//!   - Pulls one entry of a `HashMap` / `BTreeMap` source field into the DTO field:
+//!     `source.attributes.get("email").cloned().unwrap_or_default()`, so a missing key yields `Default`.
+//!   - `key` defaults to the DTO field name. Composes with the conversions, e.g. `from_str` for a
+//!     string attribute bag; conflicts with `rename`, `source_path`, and `index`. Not reversible.
+//! - `#[dto(getter = "name")]` / `#[dto(rename = "name", call)]`
+//!   - Reads the source through a method instead of a field: `source.name()`.
+//!   - Composes with `transform_fn` (`transform_fn(source.name())`) and the other conversions; the
+//!     getter's return value is used as-is (no clone in `by_ref` mode). Getter calls run before
+//!     any field is moved out of the source.
+//! - `#[dto(clone)]`
+//!   - Clones the source value instead of moving it, e.g. `source.field.clone()`.
+//!   - Lets two DTO fields read the same source field; the clone is taken before any field is moved.
+//!   - Composes with `rename` and the conversions; conflicts with `skip`.
+//! - `#[dto(unbox)]` / `#[dto(box)]`
+//!   - `unbox` reads `*source.field` out of a `Box<T>`; `box` wraps the result in `Box::new(..)`.
+//!   - Both compose with the conversions, e.g. `#[dto(unbox, into, box)]` maps `Box<A>` to `Box<B>`.
+//!   - `DtoInto` swaps them: an `unbox`ed field is boxed again and a `box`ed one dereferenced.
+//! - `#[dto(boxed_dyn = Trait)]`
+//!   - For `Box<dyn Trait>` fields: `Box::new(source.field) as Box<dyn Trait>`, the cast doing the
+//!     unsized coercion that `Into` cannot. Replaces `box` and composes with the conversions, so
+//!     `#[dto(into, boxed_dyn = Plugin)]` boxes the converted value. Not supported by `DtoInto`.
+//! - `#[dto(arc)]` / `#[dto(rc)]`
+//!   - Wrap the (converted) value for sharing: `Arc::new(source.field)`, or with `into`
+//!     `Arc::new(Into::into(source.field))`; `rc` uses `Rc::new`. Neither combines with `box`.
+//!   - `DtoInto` takes the value back out with `Arc::unwrap_or_clone` (cloning only while the
+//!     pointer is still shared, so the inner type needs `Clone`).
+//! - `#[dto(cell)]` / `#[dto(refcell)]`
+//!   - Wrap the (converted) value in `Cell::new(..)` / `RefCell::new(..)` from `core::cell`, e.g.
+//!     `#[dto(refcell, into)]` gives `RefCell::new(Into::into(source.field))`. Like `arc`, only one
+//!     wrapper applies and none combines with `box`.
+//!   - `DtoInto` takes the value out with `into_inner`; with `by_ref` it reads it with `Cell::get`
+//!     (the inner type must be `Copy`) or a `RefCell::borrow`, which panics if mutably borrowed.
+//! - `#[dto(assert_type = Type)]`
+//!   - Checks that the source field has type `Type` with a `let _: &Type = &source.field;` guard,
+//!     so an upstream type change fails at the attribute. Composes with the other mapping keys.
 //! - `#[dto(transform_fn = path::to::function)]`
 //!   - Applies the function `path::to::function(source_field)` to transform the input.
 //!   - The function must have the signature `FnOnce(SourceFieldType) -> FieldType`.
-//! - `#[dto(skip)]`
+//!   - Any callable expression works, not just a function path: a `const SHOUT: fn(String) -> String`
+//!     is a path and is called as written; `transform_fn = FORMATTERS[0]` becomes `(FORMATTERS[0])(..)`.
+//!   - Trait methods can be named with a qualified path, `transform_fn = <Ratio as Scale>::to_percent`,
+//!     without a wrapper function. `try_transform_fn` and `reverse_transform_fn` accept them too.
+//!   - With `ref` (`#[dto(transform_fn = path, ref)]`) it receives `&source.field` instead, so a
+//!     transform that only reads, like `fn len(s: &str) -> usize`, need not take ownership.
+//!   - With `opt_ref` on an `Option<T>` source it is mapped over a borrow of the contents,
+//!     `source.field.as_ref().map(path)`, so `Option<String>` becomes `Option<usize>` through
+//!     `fn(&str) -> usize` without cloning. Not supported under `fallible`.
+//!     Also accepted by `transform_expr`, `with`, and `try_transform_fn`.
+//!   - `args(..)` appends literal arguments to the call: `#[dto(transform_fn = truncate, args(10))]`
+//!     calls `truncate(source.field, 10)`. Also accepted by `try_transform_fn` and `from_source`.
+//!   - `with_fields("first", "last")` passes those source fields instead of the field's own value:
+//!     `#[dto(transform_fn = join, with_fields("first", "last"))]` calls `join(source.first, source.last)`.
+//!     The fields are moved (borrowed under `ref` or `by_ref`, cloned under `clone`), `args(..)` still
+//!     follow them, and `exhaustive` counts them as read. Not supported by `DtoInto`.
+//!   - `wrap_some` wraps the result in `Some(..)` for an `Option<T>` field; `flatten_some` unwraps an
+//!     `Option<T>` result for a `T` field with `expect`, panicking on `None`. Both work with any transform.
+//!   - `then_into` converts the result with `Into::into(path(source.field))`, for a transform that
+//!     returns something convertible into the field type (`&'static str` for a `String` field).
+//!     Unlike `into`, which converts the field in place of a transform, it runs after one.
+//!   - `element_into` converts each element of the collection the transform returns:
+//!     `#[dto(transform_fn = dedup, element_into)]` emits
+//!     `dedup(source.field).into_iter().map(Into::into).collect()`, so a whole-`Vec` step (dedup,
+//!     sort, filter) composes with element conversion. It conflicts with `then_into`.
+//!   - `or_default` reuses a `Result`- or `Option`-returning transform in an infallible mapping:
+//!     `path(source.field).unwrap_or_default()`, so a parse failure yields the field type's `Default`.
+//!     Not available under `fallible`, where the error propagates with `?` instead.
+//! - `#[dto(transform_expr = |x| x.trim().to_string())]`
+//!   - Like `transform_fn`, but with an inline closure instead of a named function.
+//!   - The closure's parameter type is inferred from the source field; composes with `from_source`.
+//! - `#[dto(transform_mut = path::to::function)]`
+//!   - Reuses an in-place normalizer `fn(&mut FieldType)`: the value is moved (or, by reference,
+//!     cloned) into a local, passed as `&mut`, and then assigned.
+//! - `#[dto(with = path::to::module)]`
+//!   - Groups a conversion pair in one module, like serde's `with`: calls
+//!     `path::to::module::from_source(source_field)`, and `DtoInto` calls `path::to::module::to_source(dto_field)`.
+//!   - Counts as a conversion, so it conflicts with `transform_fn`, `into`, and `reverse_transform_fn`.
+//! - `#[dto(transform_fn = path::to::function, from_source)]`
+//!   - Passes the whole source instead of one field: `path::to::function(&source)`.
+//!   - The function must have the signature `Fn(&Source) -> FieldType`; `rename` is rejected since no
+//!     single source field is read. The call runs before any field is moved out of the source.
+//!   - Since no source field is accessed, the source may be an **enum**: a DTO whose fields are all
+//!     `from_source` transforms (or `skip` / `default`) can flatten `#[dto(from = SourceEnum)]`.
+//! - `#[dto(skip)]` / `#[dto(default)]`
 //!   - Omits this field from the mapping; the field is initialized with `Default::default()`.
+//!   - An `Option<T>` field is initialized with `None`, so `T` need not implement `Default`.
+//!   - A bare `default` is the same key as `skip`, so writing both is a duplicate.
+//!   - Neither combines with `rename`: whether the source has a field is settled at compile time,
+//!     so "read it if present, else default" cannot be expressed. The error points at the `rename`.
+//!   - Under the struct-level `fill_default`, the field is instead left to `..Default::default()`,
+//!     taking the value the target's own `Default` impl gives it. With `only_annotated` as well,
+//!     an unannotated field is treated the same way, so only the fields you name are mapped.
+//! - `#[dto(default = expr)]`
+//!   - Like `skip`, but initializes the field with `expr` (e.g. `Vec::with_capacity(16)`),
+//!     so the field type does not need to implement `Default`.
+//! - `#[dto(with_default_fn = path::to::constructor)]`
+//!   - Like `default = expr` for a named zero-argument constructor: initializes the field with
+//!     `constructor()`, e.g. a timestamp or id generator. Conflicts with `default = ...`.
 //! - `#[dto(into)]`
 //!   - Uses `Into` to convert the source field into the DTO field type, i.e. `source_field.into()`.
+//!   - A `Vec<U>` or `Option<U>` field converts its elements instead, since no `From` impl exists
+//!     between the wrappers (`Option<A>` → `Option<B>`); `fallible` does the same with `TryInto`.
+//!     Pin the destination with `into = Type` to convert the whole value, e.g. `T` → `Option<T>`.
+//!   - Upgrade note: a bare `into` used to convert a `Vec` / `Option` field as a whole. A DTO
+//!     relying on a whole-value `From` (`T` → `Option<T>`, `String` → `Vec<u8>`) now fails to
+//!     compile; write `into = Option<T>` / `into = Vec<u8>` to keep the old conversion.
+//! - `#[dto(into = path::to::Type)]`
+//!   - Like `into`, but pins the destination: `<_ as Into<path::to::Type>>::into(source_field)`.
+//!   - Useful in generic DTOs or behind type aliases, where `Into::into` cannot be inferred.
+//! - `#[dto(into_via = path::to::Type)]`
+//!   - Two `Into` hops through an intermediate type, for conversions with no direct `From`:
+//!     `SourceId` → `Uuid` → `String` is `Into::into(<_ as Into<Uuid>>::into(source_field))`.
+//!   - Under `fallible` both hops use `TryInto` with `?`; `DtoInto` goes back through the same type.
+//! - `#[dto(auto_into)]` (deprecated: write `into`)
+//!   - Converts element-wise when the DTO field type is `Vec<U>`
+//!     (`source_field.into_iter().map(Into::into).collect()`) or `Option<U>` (`source_field.map(Into::into)`).
+//!   - Any other field type falls back to plain `Into::into`. Since `into` gained element-wise
+//!     conversion the two keys generate the same code; `auto_into` is still accepted so
+//!     existing DTOs keep compiling.
+//! - `#[dto(collect)]`
+//!   - Converts every element with `Into` and collects into the field type, whatever collection it
+//!     is: `source_field.into_iter().map(Into::into).collect()`.
+//!   - Covers collection changes such as `Vec<T>` → `HashSet<U>` / `BTreeSet<U>` / `VecDeque<U>`.
+//! - `#[dto(map_values)]` / `#[dto(map_keys_values)]`
+//!   - For `HashMap<K, V>` / `BTreeMap<K, V>` fields: rebuilds the map with `Into` applied to every
+//!     value (`map_values`) or to every key and value (`map_keys_values`).
+//!   - Any other field type is rejected at the field type.
+//! - `#[dto(unwrap_or = expr)]` / `#[dto(unwrap_or_default)]`
+//!   - Flattens an `Option<T>` source into a `T` field: `source.field.unwrap_or(expr)` or
+//!     `source.field.unwrap_or_default()`. Counts as the field's conversion.
+//!   - `DtoInto` maps the value back as `Some(dto.field)`.
+//! - `#[dto(unwrap_result)]`
+//!   - Flattens a `Result<T, E>` source into a `T` field. `DtoTryFrom` (and `fallible`) propagates the
+//!     `Err` with `?`, converting it into the struct `error`; `DtoFrom` panics on `Err`.
+//!   - With `unwrap_or = expr` / `unwrap_or_default` the `Err` falls back instead:
+//!     `source.field.unwrap_or(expr)` on the `Result`. `DtoInto` maps the value back as `Ok(dto.field)`.
+//! - `#[dto(cow)]`
+//!   - For `Cow<'a, B>` fields: `Cow::Owned(source.field)` when mapping an owned source, and
+//!     `Cow::Borrowed(source.field.borrow())` when mapping by reference. Borrowing requires the DTO's
+//!     lifetime to be the source borrow, e.g. `#[dto(from = &'a Source)]` on `struct Dto<'a>`.
+//!   - `DtoInto` converts back with `Cow::into_owned`.
+//! - `#[dto(as = u8)]`
+//!   - Numeric cast: `source.field as u8`, for deliberate narrowing or widening where no lossless
+//!     `From` exists (`i32` → `u8`, `f64` → `i64`).
+//!   - The cast is **lossy**: out-of-range integers wrap and floats truncate toward zero (saturating
+//!     at the bounds), exactly like `as`. Prefer `try_into` under `DtoTryFrom` when that must fail.
+//!   - Counts as the field's conversion; `DtoInto` casts back with `dto.field as _`.
+//! - `#[dto(try_into_expect = "port numbers fit in u16")]`
+//!   - A `TryFrom`-only conversion in an infallible impl:
+//!     `TryInto::try_into(source.field).expect("port numbers fit in u16")`.
+//!   - **Panics** with that message (and the error's `Debug` output) on `Err`, so use it only where
+//!     the conversion cannot fail in practice; `try_into` under `DtoTryFrom` propagates instead.
+//!   - Works in every forward derive; `DtoInto` needs `reverse_transform_fn`.
+//! - `#[dto(naive_to_utc)]` / `#[dto(utc_to_naive)]` (cargo feature `chrono`)
+//!   - `NaiveDateTime` → `DateTime<Utc>` (the naive value is read as UTC), or back via `naive_utc()`.
+//!   - An `Option` field converts the inner value; `DtoInto` applies the opposite conversion.
+//!   - The generated code names `::chrono`, so the using crate depends on `chrono` itself.
+//! - `#[dto(from_str)]` / `#[dto(from_str_or_default)]`
+//!   - Parses a `String` / `&str` source with the field type's `FromStr`, e.g. a status string into
+//!     an enum: `FromStr::from_str(&source.field)`.
+//!   - `DtoFrom` panics on a parse failure, `DtoTryFrom` (and `fallible`) propagates it with `?`, and
+//!     `from_str_or_default` falls back to `Default::default()` instead.
+//!   - `DtoInto` converts back with `ToString::to_string`, so the field type needs `Display`.
 //!
 //! ### Usage Example
 //!
@@ -90,8 +367,8 @@
 //!
 //! ### Additional Example: Collections
 //!
-//! Converting collections and options is handled explicitly with `transform_fn` helpers.
-//! This keeps behavior clear without extra macro features.
+//! Converting collections and options is handled explicitly with `transform_fn` helpers,
+//! or with `#[dto(into)]` for the common `Vec<T>` / `Option<T>` element conversions.
 //!
 //! ```rust
 //! use simple_dto_mapper_derive::DtoFrom;
@@ -109,6 +386,7 @@
 //!         pub id: String,
 //!         pub title: String,
 //!         pub labels: Vec<SourceTag>,           // -> Vec<DtoTag>
+//!         pub labels_copy: Vec<SourceTag>,      // -> Vec<DtoTag> via into
 //!         pub keywords: Vec<String>,            // same type
 //!         pub author: Option<SourceAuthor>,      // -> Option<DtoAuthor>
 //!         pub published_at: Option<chrono::DateTime<chrono::Utc>>, // -> Option<DateTime<Utc>>
@@ -122,7 +400,7 @@
 //!     pub struct DtoAuthor { pub name: String }
 //!     impl From<SourceAuthor> for DtoAuthor { fn from(a: SourceAuthor) -> Self { DtoAuthor { name: a.name } } }
 //!
-//!     // Helpers for transform_fn
+//!     // Helpers for transform_fn (the `simple_dto_mapper` crate ships these as `helpers`)
 //!     pub fn vec_into<T, U>(v: Vec<T>) -> Vec<U> where U: From<T> {
 //!         v.into_iter().map(Into::into).collect()
 //!     }
@@ -153,35 +431,250 @@
 //!     #[dto(transform_fn = types::opt_into::<types::SourceAuthor, types::DtoAuthor>)]
 //!     author: Option<types::DtoAuthor>,
 //!
+//!     /// Vec<SourceTag> → Vec<DtoTag> without a helper
+//!     #[dto(rename = "labels_copy", into)]
+//!     tags_auto: Vec<types::DtoTag>,
+//!
 //!     published_at: Option<DateTime<Utc>>,
 //! }
 //! ```
 //!
+//! ### Fallible Conversions: `DtoTryFrom`
+//!
+//! The sibling `DtoTryFrom` derive generates `impl TryFrom<Source> for Target` instead.
+//! It accepts every `DtoFrom` attribute plus:
+//! - `#[dto(error = Type)]` (struct-level, required)
+//!   - The associated `TryFrom::Error` type of the generated impl.
+//! - `#[dto(try_into)]`
+//!   - Calls `::core::convert::TryInto::try_into(source_field)?`.
+//!   - Requires `TryFrom<SourceFieldType> for FieldType` whose error converts into the struct `error` type.
+//! - `#[dto(try_transform_fn = path::to::function)]`
+//!   - Calls `path::to::function(source_field)?`.
+//!   - The function must have the signature `FnOnce(SourceFieldType) -> Result<FieldType, E>`
+//!     where `E` converts into the struct `error` type.
+//! - `#[dto(try_transform_fn = path, map_err = to_dto_err)]`
+//!   - Calls `path(source_field).map_err(to_dto_err)?`, so transforms with unrelated error types
+//!     share one conversion instead of each needing `From<E> for Error`.
+//!   - Also applies to `transform_fn` / `transform_expr` / `with` under `fallible`; any other field is
+//!     an error.
+//!
+//! Direct, `rename`, `transform_fn`, `skip`, `default`, and `into` fields behave exactly as in `DtoFrom`.
+//!
+//! ```rust
+//! use simple_dto_mapper_derive::DtoTryFrom;
+//!
+//! mod types {
+//!     pub struct Player { pub name: String, pub level: i32 }
+//!
+//!     #[derive(Debug, PartialEq)]
+//!     pub enum Rank { Junior, Senior }
+//!
+//!     impl TryFrom<i32> for Rank {
+//!         type Error = String;
+//!         fn try_from(v: i32) -> Result<Self, Self::Error> {
+//!             match v {
+//!                 0 => Ok(Rank::Junior),
+//!                 1 => Ok(Rank::Senior),
+//!                 _ => Err(format!("unknown rank {v}")),
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! #[derive(DtoTryFrom, Debug)]
+//! #[dto(from = types::Player, error = String)]
+//! struct PlayerDto {
+//!     name: String,
+//!
+//!     /// `try_into`: uses `TryFrom<i32> for Rank`
+//!     #[dto(rename = "level", try_into)]
+//!     rank: types::Rank,
+//! }
+//!
+//! let ok = PlayerDto::try_from(types::Player { name: "Alice".into(), level: 1 }).unwrap();
+//! assert_eq!(ok.rank, types::Rank::Senior);
+//!
+//! let err = PlayerDto::try_from(types::Player { name: "Bob".into(), level: 9 }).unwrap_err();
+//! assert_eq!(err, "unknown rank 9");
+//! ```
+//!
+//! `#[dto(error(generate))]` replaces the single error type with a generated
+//! `<Target>ConversionError` enum (`#[derive(Debug)]`, same visibility as the target) holding one
+//! variant per fallible field, named after it in `PascalCase`. Each fallible field names its own
+//! error with `#[dto(error = Type)]`; its `?` converts into that type, and the error is wrapped in
+//! the field's variant (`PlayerDtoConversionError::Rank(e)`), so a failure says which field it came from.
+//!
+//! `#[dto(fallible, error = Type)]` on a `DtoFrom` struct generates the same `TryFrom` impl, and also
+//! makes the ordinary conversions fallible: `transform_fn` / `transform_expr` / `with` must return
+//! `Result<FieldType, E>` and are followed by `?`, and `into` calls `TryInto::try_into(..)?`.
+//! `try_into` and `try_transform_fn` are accepted there as well. Under `DtoTryFrom`, by contrast,
+//! `into` stays the infallible `Into`, so one DTO mixes guaranteed (`into`) and fallible
+//! (`try_into`) conversions without the `error` type needing `From<Infallible>`.
+//!
+//! ### Reverse Mapping: `DtoInto`
+//!
+//! The `DtoInto` derive reads the same attributes and generates the inverse,
+//! `impl From<Target> for Source`, so a DTO can be mapped back without a second attribute vocabulary:
+//! - Direct and `rename` fields swap direction: `source.orig_name = dto.field`.
+//! - `into` / `auto_into` fields convert back with `Into` (`From<FieldType> for SourceFieldType` must exist).
+//! - `transform_fn` / `transform_expr` / `transform_mut` (and `try_*`) fields are not invertible; they need
+//!   `#[dto(reverse_transform_fn = path)]` with the signature `FnOnce(FieldType) -> SourceFieldType`.
+//! - `skip` / `default` fields are dropped; they have no source-side counterpart.
+//! - Source fields the DTO does not carry need a value: the struct-level `#[dto(reverse_default)]`
+//!   fills them with `..Default::default()` (requires `Source: Default`).
+//!
+//! `DtoInto` can sit next to `DtoFrom` or `DtoTryFrom` on the same struct; each derive ignores
+//! the keys that only matter to the other direction.
+//!
+//! ```rust
+//! use simple_dto_mapper_derive::{DtoFrom, DtoInto};
+//!
+//! mod types {
+//!     #[derive(Default)]
+//!     pub struct Price { pub cents: u64, pub currency: String }
+//!
+//!     pub fn to_units(c: u64) -> f64 { c as f64 / 100.0 }
+//!     pub fn to_cents(u: f64) -> u64 { (u * 100.0).round() as u64 }
+//! }
+//!
+//! #[derive(DtoFrom, DtoInto)]
+//! #[dto(from = types::Price, reverse_default)]
+//! struct PriceDto {
+//!     #[dto(rename = "cents", transform_fn = types::to_units, reverse_transform_fn = types::to_cents)]
+//!     amount: f64,
+//! }
+//!
+//! let price: types::Price = PriceDto { amount: 2.5 }.into();
+//! assert_eq!(price.cents, 250);
+//! assert_eq!(price.currency, ""); // filled by `reverse_default`
+//! ```
+//!
 //! ### Error Messages
 //!
 //! The derive macro produces clear, span-accurate diagnostics for common mistakes:
 //! - Missing struct attribute: `#[dto(from = Type)]`.
-//! - Unsupported item shapes: only named-field structs are supported (tuple/unit structs and enums are rejected).
-//! - Unknown field attribute keys: reports the unknown key and the allowed set (`rename`, `transform_fn`, `skip`, `into`).
-//! - Duplicate attributes on a field: `rename`, `transform_fn`, `skip`, or `into` repeated.
-//! - Conflicting attributes on a field: `skip` cannot appear with any other attribute; `transform_fn` conflicts with `into`.
-//! - Invalid `rename` value: empty string is rejected.
-//! - Unknown/duplicate struct-level keys: only `from` is allowed at the struct level.
+//! - Unsupported item shapes: only named-field and tuple structs, and enums with a single struct-like
+//!   variant, are supported. Unit structs, other enums (map each variant by hand), and unions are
+//!   rejected with a message naming the shape.
+//! - Unknown field attribute keys: reports the unknown key and lists every field key the derive
+//!   accepts (the field-level attributes documented above). `DtoTryFrom` and `DtoInto` accept the
+//!   same keys; `DtoFrom` accepts `try_transform_fn`, `try_into` and `error` only with `fallible`.
+//! - Duplicate attributes on a field: any field key repeated. A repeated `rename` underlines both
+//!   occurrences.
+//! - Conflicting attributes on a field: `skip`/`default` cannot appear with `rename` or any conversion; `transform_fn` conflicts with `into` (both keys are underlined);
+//!   at most one of `transform_fn`, `transform_expr`, `transform_mut`, `with`, `try_transform_fn`, `into`, `into_via`, `auto_into`,
+//!   `collect`, `map_values`, `map_keys_values`, `unwrap_or`, `unwrap_or_default`, `unwrap_result`, `cow`, `as`, `from_str`,
+//!   `from_str_or_default`, `try_into_expect`, or `try_into` may be set (`unwrap_result` pairs with `unwrap_or` / `unwrap_or_default`); the error points at the second one.
+//! - `map_values` / `map_keys_values` on a field whose type is not a `HashMap` or `BTreeMap`, or `cow`
+//!   on a field whose type is not a `Cow`.
+//! - `with_fields` without a path transform, with an empty list, or with `rename` / `source_path` /
+//!   `from_source`.
+//! - `boxed_dyn` together with `box`, or on `DtoInto`; more than one of `arc`, `rc`, `cell`, or `refcell`,
+//!   or any of them with `box` / `boxed_dyn`.
+//! - Fallible keys on `DtoFrom`: `try_into` and `try_transform_fn` require `#[derive(DtoTryFrom)]`
+//!   or `#[dto(fallible)]`.
+//! - Missing `#[dto(error = Type)]` on a `DtoTryFrom` or `fallible` struct, or `error` on a `DtoFrom`
+//!   struct without `fallible`.
+//! - With `error(generate)`, a fallible field without its own `error = Type` (or one on a field
+//!   that cannot fail); a field-level `error` without `error(generate)`.
+//! - `from_source` without a transform, or combined with `rename`; `ref`, `then_into`,
+//!   `element_into` or `or_default` without a transform; `element_into` with `then_into`;
+//!   `opt_ref` without `transform_fn`, with `ref` / `with_fields`, or under `fallible`.
+//! - A `rename` naming a field the source lacks: the compiler's "no field" error underlines the
+//!   string literal in the attribute.
+//! - A source field whose type differs from `assert_type`: the mismatch underlines the attribute's type.
+//! - A `skip`ped field whose type lacks `Default`: the trait-bound error underlines the field name.
+//! - A `transform_fn` (or `try_transform_fn` / `reverse_transform_fn`) path that is not a function:
+//!   the error underlines the path in the attribute rather than the field.
+//! - A `transform_fn` / `with` / `try_transform_fn` returning the wrong type: the mismatch underlines
+//!   the path, with the field's type marked as the expected one. An `async fn` is reported the same
+//!   way, as "expected `FieldType`, found future".
+//! - `DtoInto` on a field whose forward conversion is a transform without `reverse_transform_fn`.
+//! - A `rename` that is empty or not a valid field identifier (`"full name"`, `"123abc"`, `"a-b"`, or
+//!   `self` / `super` / `crate`, which have no raw form); it is checked as it is parsed, since the
+//!   macro cannot compare it against the source's fields.
+//! - Invalid `source_path` value: every dot-separated segment must be a field name or tuple index.
+//! - Invalid `bound` value: the string must parse as comma-separated where-predicates.
+//! - The same `from` type listed twice, or reference and owned `from` types mixed.
+//! - An empty `from =` value, or `from = Self`.
+//! - Unknown `rename_all` case (kebab-case is rejected since it cannot name a field).
+//! - A `strip_prefix` that is empty or could not start a field name.
+//! - `both_refs` with a path transform, `by_ref`, or a reference `from` type.
+//! - `Arc` / `Rc` `from` types mixed with plain ones, or combined with `DtoInto` or `both_refs`.
+//! - Unknown/duplicate struct-level keys: the error lists every struct-level key (the struct
+//!   attributes documented above). `from` may repeat once per distinct source and `bound` / `where`
+//!   accumulate; any other key repeated is an error.
+//! - A `debug_assert_invariant` string that does not parse as an expression.
+//! - With `const_fn`, a field whose mapping is not `const` (transforms, `into`, a bare `skip`, ...), or a
+//!   fallible, by-ref or multi-source derive.
+//! - With `only_annotated` but no `fill_default`, a field without any `#[dto(...)]` attribute.
+//! - `direct` combined with `skip`, `rename`, `transform_fn`, `into`, or another key that changes the mapping.
+//! - `generics_from_source` naming a parameter the DTO also declares, or finding none in `from`.
+//! - With `exhaustive`, a source field no DTO field reads: the compiler's "pattern does not mention
+//!   field" error underlines the `exhaustive` key.
 //!
 //! See `tests/ui` for compile-fail cases that exercise each diagnostic.
 //!
 //! ### Limitations
 //!
-//! - **Named-field structs only**: tuple/unit structs and enums are not supported.
-//! - **Structs only**: traits/unions/enums cannot derive `DtoFrom`.
-//! - **Owned-only mapping**: generates `impl From<Source> for Target` (no zero-copy/by-ref mode).
-//! - **`transform_fn` signature**: must be `FnOnce(SourceFieldType) -> FieldType` (owned input, owned output).
+//! - **Named-field or tuple structs only**: unit structs and enums are not supported, except an enum
+//!   with exactly one struct-like variant (`enum Envelope { Event { id: u64 } }`), which `DtoFrom` /
+//!   `DtoTryFrom` fill as `Self::Event { .. }` with the usual field keys (`fill_default` aside).
+//!   `DtoInto` needs a struct.
+//! - **Tuple targets read positionally**: without `rename`, tuple field `N` reads `source.N`, so a
+//!   named-field source needs `#[dto(rename = "field")]` on every position. Each position otherwise
+//!   takes every field key (`index`, `into`, `transform_fn`, `skip`, ...), e.g.
+//!   `struct Dto(#[dto(index = 1, into)] DtoStatus, #[dto(skip)] u32)`.
+//! - **No unions or multi-variant enums**: neither can derive `DtoFrom`.
+//! - **`no_std`**: the generated code names only `core` and `alloc` paths, so it compiles in a
+//!   `#![no_std]` crate. Keys that allocate (`box`, `arc` / `rc`, `unbox` in `DtoInto`, `cow`, `from_str` in
+//!   `DtoInto`, `collection_helper`) reach `alloc` through a local `extern crate alloc`, which the
+//!   target must provide.
+//! - **Hygienic binding**: the generated impl names its parameter `__dto_source`, so a module,
+//!   type, or `const` called `source` can appear in `from`, transform paths, and `default = expr`.
+//! - **Owned or cloned**: generates `impl From<Source> for Target` (moves fields) or, with `by_ref`,
+//!   `impl From<&Source> for Target` (clones fields). Only reference-typed fields borrow; an owned
+//!   field such as `String` is always cloned out of a borrowed source.
+//! - **`transform_fn` signature**: by default `FnOnce(SourceFieldType) -> FieldType`, taking the
+//!   moved value. `ref`, `opt_ref`, and `by_ref` pass a reference instead, `from_source` the whole
+//!   source, and `args(..)` / `with_fields(..)` change the argument list; the result must still be
+//!   the field type unless `wrap_some`, `flatten_some`, `then_into`, `element_into`, or `or_default`
+//!   adapt it.
+//! - **No async transforms**: `From` is synchronous, so an `async fn` transform cannot be awaited;
+//!   await it before converting, or map the resolved value with a synchronous function.
 //! - **`into` requires `From`**: `From<SourceFieldType> for FieldType` must exist.
-//! - **`skip` requires `Default`**: the target field type must implement `Default`.
-//! - **No automatic element mapping**: collections/options do not map inner elements automatically; use `transform_fn`.
-//! - **No `auto_into` / `try_into`**: conversions are explicit per-field with `#[dto(into)]`.
+//! - **`skip` requires `Default`**: the target field type must implement `Default` (use `default = expr`
+//!   otherwise); an `Option<T>` field is set to `None` instead.
+//! - **No implicit element mapping**: collections/options only map inner elements when the field opts in
+//!   with `#[dto(into)]` (`Vec<U>` and `Option<U>` only); otherwise use `collect` or `transform_fn`.
+//! - **`into`, `auto_into`, and `map_values` inspect the written type**: detection is by the last path segment
+//!   (`Vec`/`Option`/`HashMap`/`BTreeMap`), so a type alias for `Vec<U>` falls back to plain `Into::into`
+//!   and an alias for a map is rejected by `map_values`.
+//! - **`try_into` / `try_transform_fn` are fallible-only**: they need `DtoTryFrom` or `DtoFrom` with `fallible`.
+//! - **Source generics come from the DTO**: every generic parameter used in `from = ...` must be declared
+//!   on the DTO struct itself, unless `generics_from_source` declares it on the impl instead.
 //! - **Field existence is validated by the compiler**: a missing/renamed source field causes a compile error at the attribute span.
 //!
+//! ### Tuple-struct Targets
+//!
+//! Tuple structs are populated positionally. Each position accepts the same field attributes;
+//! `rename` names the source field to read, otherwise position `N` reads `source.N`.
+//!
+//! ```rust
+//! use simple_dto_mapper_derive::DtoFrom;
+//!
+//! mod types {
+//!     pub struct User { pub id: String, pub age: u32 }
+//! }
+//!
+//! #[derive(DtoFrom)]
+//! #[dto(from = types::User)]
+//! struct IdDto(#[dto(rename = "id")] String, #[dto(rename = "age")] u32);
+//!
+//! let dto: IdDto = types::User { id: "u1".into(), age: 30 }.into();
+//! assert_eq!(dto.1, 30);
+//! ```
+//!
 //! ### Mapping Rules (at a glance)
 //!
 //! - **Default (owned move)**  
@@ -192,22 +685,137 @@
 //!     - `#[dto(transform_fn = ...)]` provides an explicit conversion
 //!
 //! - **Field attributes**
+//!   - `#[dto(direct)]`  
+//!     Maps the same-named source field as-is; the explicit form of the default.
+//!
 //!   - `#[dto(rename = "orig_name")]`  
 //!     Reads from a **different source field name** (type must still be compatible).
 //!
+//!   - `#[dto(source_path = "a.b")]`  
+//!     Reads from a **nested source field**, `source.a.b`. Not reversible by `DtoInto`.
+//!
+//!   - `#[dto(index = N)]`  
+//!     Reads the **positional source field** `source.N` of a tuple-struct source.
+//!
+//!   - `#[dto(from_map = "map", key = "k")]`  
+//!     Reads the **map entry** `source.map.get("k").cloned().unwrap_or_default()`. Not reversible by `DtoInto`.
+//!
+//!   - `#[dto(getter = "name")]`  
+//!     Reads from a **source method**, `source.name()`. Not reversible by `DtoInto`.
+//!
+//!   - `#[dto(clone)]`  
+//!     Reads `source.orig_name.clone()` instead of moving the field.
+//!
+//!   - `#[dto(unbox)]` / `#[dto(box)]`  
+//!     Reads `*source.orig_name` / assigns `Box::new(value)`.
+//!
+//!   - `#[dto(boxed_dyn = Trait)]`  
+//!     Assigns `Box::new(value) as Box<dyn Trait>`.
+//!
+//!   - `#[dto(arc)]` / `#[dto(rc)]`  
+//!     Assigns `Arc::new(value)` / `Rc::new(value)`.
+//!   - `#[dto(cell)]` / `#[dto(refcell)]`  
+//!     Assigns `Cell::new(value)` / `RefCell::new(value)`.
+//!
 //!   - `#[dto(transform_fn = path::to::function)]`  
 //!     Calls `function(source.orig_name)` before assignment.  
-//!     Signature: `FnOnce(SourceFieldType) -> FieldType`.
+//!     Signature: `FnOnce(SourceFieldType) -> FieldType`; with `ref`, `function(&source.orig_name)`;
+//!     with `opt_ref`, `source.orig_name.as_ref().map(function)`.
+//!     With `args(a, b)`, calls `function(source.orig_name, a, b)`; with `with_fields("x", "y")`,
+//!     `function(source.x, source.y)`. `wrap_some` / `flatten_some`
+//!     wrap the result in `Some(..)` / unwrap it with `expect`; `then_into` passes it through
+//!     `Into::into`, and `or_default` falls back with `unwrap_or_default()`.
+//!
+//!   - `#[dto(transform_expr = |x| ...)]`  
+//!     Calls the closure with `source.orig_name`, like `transform_fn`.
+//!
+//!   - `#[dto(transform_mut = path)]`  
+//!     `{ let mut v = source.orig_name; path(&mut v); v }`.
+//!
+//!   - `#[dto(with = path::to::module)]`  
+//!     Calls `module::from_source(source.orig_name)` (and `module::to_source` for `DtoInto`).
 //!
-//!   - `#[dto(skip)]`  
+//!   - `#[dto(transform_fn = path::to::function, from_source)]`  
+//!     Calls `function(&source)` with the whole source.  
+//!     Signature: `Fn(&Source) -> FieldType`.
+//!
+//!   - `#[dto(skip)]` / `#[dto(default)]`  
 //!     Skips mapping; initializes the field with `Default::default()`.
 //!
+//!   - `#[dto(default = expr)]`  
+//!     Skips mapping; initializes the field with `expr`.
+//!
+//!   - `#[dto(with_default_fn = path)]`  
+//!     Skips mapping; initializes the field with `path()`.
+//!
 //!   - `#[dto(into)]`  
 //!     Calls `::core::convert::Into::into(source.orig_name)`.  
-//!     Requires `From<SourceFieldType> for FieldType` and is infallible.
+//!     Requires `From<SourceFieldType> for FieldType` and is infallible; `Vec<U>` / `Option<U>`
+//!     fields convert their elements; pin `into = Type` to convert the whole value instead.
+//!
+//!   - `#[dto(into = Type)]`  
+//!     Calls `<_ as Into<Type>>::into(source.orig_name)`.
+//!
+//!   - `#[dto(into_via = Type)]`  
+//!     Calls `Into::into(<_ as Into<Type>>::into(source.orig_name))`.
+//!
+//!   - `#[dto(auto_into)]` (deprecated)  
+//!     `Vec<U>` → `source.orig_name.into_iter().map(Into::into).collect()`,  
+//!     `Option<U>` → `source.orig_name.map(Into::into)`, otherwise `Into::into(source.orig_name)`.
+//!
+//!   - `#[dto(collect)]`  
+//!     `source.orig_name.into_iter().map(Into::into).collect()` into any `FromIterator` field type.
+//!
+//!   - `#[dto(unwrap_or = expr)]` / `#[dto(unwrap_or_default)]`  
+//!     `source.orig_name.unwrap_or(expr)` / `source.orig_name.unwrap_or_default()`.
+//!
+//!   - `#[dto(unwrap_result)]`  
+//!     `source.orig_name?` when fallible, a panic on `Err` otherwise; composes with `unwrap_or`.
+//!
+//!   - `#[dto(cow)]`  
+//!     `Cow::Owned(source.orig_name)`, or `Cow::Borrowed(..)` when mapping by reference.
+//!
+//!   - `#[dto(as = Type)]`  
+//!     `source.orig_name as Type` (a lossy numeric cast).
+//!
+//!   - `#[dto(naive_to_utc)]` / `#[dto(utc_to_naive)]` (feature `chrono`)  
+//!     `DateTime::<Utc>::from_naive_utc_and_offset(source.orig_name, Utc)` / `source.orig_name.naive_utc()`.
+//!
+//!   - `#[dto(from_str)]` / `#[dto(from_str_or_default)]`  
+//!     `source.orig_name.parse()`, panicking (or `?` when fallible) / defaulting on failure.
+//!
+//!   - `#[dto(map_values)]` / `#[dto(map_keys_values)]`  
+//!     `source.orig_name.into_iter().map(|(k, v)| (k, v.into())).collect()` (keys converted too with
+//!     `map_keys_values`); the field type must be a `HashMap` or `BTreeMap`.
 //!
 //! - **Struct attribute (required)**  
-//!   - `#[dto(from = Type)]` — Specifies the **source struct** for the mapping.
+//!   - `#[dto(from = Type)]` — Specifies the **source struct** for the mapping; repeat it for several sources.
+//!   - `#[dto(error = Type)]` — `DtoTryFrom` or `fallible` only; the `TryFrom::Error` type.
+//!   - `#[dto(error(generate))]` — generate `<Target>ConversionError` with a variant per fallible field.
+//!   - `#[dto(fallible)]` — `DtoFrom` generates `TryFrom`; transforms return `Result` and `into` uses `TryInto`.
+//!   - `#[dto(by_ref)]` — map from `&Source`, cloning fields instead of moving them.
+//!   - `#[dto(both_refs)]` — generate both the owned and the `&Source` impl.
+//!   - `#[dto(bound = "...")]` / `#[dto(where = "...")]` — extra `where` predicates for the generated impl.
+//!   - `#[dto(generics_from_source)]` — declare the type parameters of `from` on the impl.
+//!   - `#[dto(reverse_default)]` — `DtoInto` only; fill unmapped source fields with `Default::default()`.
+//!   - `#[dto(fill_default)]` — leave `skip` fields to `..Default::default()`; the target needs `Default`.
+//!   - `#[dto(only_annotated)]` — every field needs a `#[dto(...)]` attribute (`direct` for a 1:1 field).
+//!     Alone, an unannotated field is an error; with `fill_default`, it is left to the target's `Default`.
+//!   - `#[dto(rename_all = "camelCase")]` — source field names are the DTO names in that case.
+//!   - `#[dto(strip_prefix = "user_")]` — source field names are the DTO names with this prefix.
+//!   - `#[dto(collection_helper)]` — add `Target::from_vec` / `Target::map_all` (and their `try_` forms).
+//!   - `#[dto(inherent)]` — add `Target::map_from` / `Target::try_map_from`.
+//!   - `#[dto(const_fn)]` — add `const fn Target::from_const`; only `const`-compatible fields.
+//!   - `#[dto(post_build = path)]` — call `path(&mut target, &source)` after the fields are assigned.
+//!   - `#[dto(debug_assert_invariant = "expr")]` — `debug_assert!(expr)` on the result, `self` being the target.
+//!   - `#[dto(exhaustive)]` — fail to compile if a source field is not read by any DTO field.
+//!   - `#[dto(auto_nested)]` — direct fields use `Into`, converting nested DTOs implicitly.
+//!   - `#[dto(no_inline)]` — omit the default `#[inline]` on the generated function.
+//!   - `#[dto(debug)]` — print the generated code to stderr at compile time.
+//!   - `#[dto(cfg_feature = "name")]` — generate the impls only under `#[cfg(feature = "name")]`.
+//!
+//! - **Reverse field attribute**
+//!   - `#[dto(reverse_transform_fn = path)]` — `DtoInto` only; calls `path(dto.field)` for `source.orig_name`.
 //!
 //! Violations of these rules cause **compile-time errors** with span-accurate diagnostics (see the “Error Messages” section).
 
@@ -216,29 +824,522 @@ use proc_macro2::Span;
 use quote::{quote, quote_spanned};
 use syn::{parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Fields, Ident, Path};
 
+/// Which trait the derive implements for the target struct.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ImplKind {
+    /// `DtoFrom`: `impl From<Source> for Target`.
+    From,
+    /// `DtoTryFrom`: `impl TryFrom<Source> for Target`.
+    TryFrom,
+    /// `DtoInto`: the reverse mapping, `impl From<Target> for Source`.
+    Into,
+}
+
+impl ImplKind {
+    fn derive_name(self) -> &'static str {
+        match self {
+            ImplKind::From => "DtoFrom",
+            ImplKind::TryFrom => "DtoTryFrom",
+            ImplKind::Into => "DtoInto",
+        }
+    }
+
+    /// Struct-level `#[dto(...)]` keys accepted by this derive.
+    ///
+    /// Every derive accepts the same set: `error`/`fallible` switch `DtoFrom` to a fallible impl, and
+    /// `DtoInto` sits next to either forward derive, so it tolerates them too.
+    fn struct_keys(self) -> &'static [&'static str] {
+        &[
+            "from",
+            "error",
+            "fallible",
+            "by_ref",
+            "both_refs",
+            "bound",
+            "where",
+            "rename_all",
+            "strip_prefix",
+            "reverse_default",
+            "fill_default",
+            "only_annotated",
+            "no_inline",
+            "collection_helper",
+            "inherent",
+            "const_fn",
+            "post_build",
+            "debug_assert_invariant",
+            "exhaustive",
+            "auto_nested",
+            "generics_from_source",
+            "debug",
+            "cfg_feature",
+        ]
+    }
+
+    /// Field-level `#[dto(...)]` keys accepted by this derive, in documentation order.
+    ///
+    /// `DtoFrom` takes the fallible-only keys under `#[dto(fallible)]`, as `require_fallible` does.
+    fn field_keys(self, fallible: bool) -> impl Iterator<Item = &'static FieldKey> {
+        let fallible_keys = if self != ImplKind::From || fallible {
+            FALLIBLE_FIELD_KEYS
+        } else {
+            &[]
+        };
+        FIELD_KEYS.iter().chain(fallible_keys)
+    }
+}
+
+/// A field-level `#[dto(...)]` key, as listed in diagnostics.
+struct FieldKey {
+    name: &'static str,
+    /// The key produces the field's value, so at most one such key may be set on a field.
+    conversion: bool,
+}
+
+impl FieldKey {
+    const fn plain(name: &'static str) -> Self {
+        FieldKey {
+            name,
+            conversion: false,
+        }
+    }
+
+    const fn conversion(name: &'static str) -> Self {
+        FieldKey {
+            name,
+            conversion: true,
+        }
+    }
+}
+
+/// Field keys every derive accepts.
+const FIELD_KEYS: &[FieldKey] = &[
+    FieldKey::plain("direct"),
+    FieldKey::plain("rename"),
+    FieldKey::plain("source_path"),
+    FieldKey::plain("index"),
+    FieldKey::plain("from_map"),
+    FieldKey::plain("key"),
+    FieldKey::plain("getter"),
+    FieldKey::plain("call"),
+    FieldKey::plain("clone"),
+    FieldKey::plain("ref"),
+    FieldKey::plain("opt_ref"),
+    FieldKey::plain("unbox"),
+    FieldKey::plain("box"),
+    FieldKey::plain("boxed_dyn"),
+    FieldKey::plain("arc"),
+    FieldKey::plain("rc"),
+    FieldKey::plain("cell"),
+    FieldKey::plain("refcell"),
+    FieldKey::plain("assert_type"),
+    FieldKey::conversion("transform_fn"),
+    FieldKey::plain("args"),
+    FieldKey::plain("with_fields"),
+    FieldKey::plain("wrap_some"),
+    FieldKey::plain("flatten_some"),
+    FieldKey::plain("then_into"),
+    FieldKey::plain("or_default"),
+    FieldKey::plain("element_into"),
+    FieldKey::conversion("transform_expr"),
+    FieldKey::conversion("transform_mut"),
+    FieldKey::conversion("with"),
+    FieldKey::plain("map_err"),
+    FieldKey::plain("reverse_transform_fn"),
+    FieldKey::plain("from_source"),
+    FieldKey::plain("skip"),
+    FieldKey::plain("default"),
+    FieldKey::plain("with_default_fn"),
+    FieldKey::conversion("into"),
+    FieldKey::conversion("into_via"),
+    FieldKey::conversion("auto_into"),
+    FieldKey::conversion("collect"),
+    FieldKey::conversion("map_values"),
+    FieldKey::conversion("map_keys_values"),
+    FieldKey::conversion("unwrap_or"),
+    FieldKey::conversion("unwrap_or_default"),
+    FieldKey::conversion("unwrap_result"),
+    FieldKey::conversion("cow"),
+    FieldKey::conversion("as"),
+    FieldKey::conversion("from_str"),
+    FieldKey::conversion("from_str_or_default"),
+    FieldKey::conversion("naive_to_utc"),
+    FieldKey::conversion("utc_to_naive"),
+    FieldKey::conversion("try_into_expect"),
+];
+
+/// Field keys only a fallible derive accepts: `DtoTryFrom`, `DtoInto`, or `DtoFrom` with `fallible`.
+const FALLIBLE_FIELD_KEYS: &[FieldKey] = &[
+    FieldKey::conversion("try_transform_fn"),
+    FieldKey::conversion("try_into"),
+    FieldKey::plain("error"),
+];
+
+/// Joins keys as "`a`, `b`, or `c`" for diagnostics.
+fn list_keys(keys: &[&str]) -> String {
+    let quoted: Vec<String> = keys.iter().map(|k| format!("`{k}`")).collect();
+    match quoted.split_last() {
+        Some((last, [only])) => format!("{only} or {last}"),
+        Some((last, rest)) if !rest.is_empty() => format!("{}, or {last}", rest.join(", ")),
+        Some((last, _)) => last.clone(),
+        None => String::new(),
+    }
+}
+
+#[derive(Clone)]
+struct StructAttrs {
+    /// Every `from = Type`, in declaration order; one impl is generated per source.
+    sources: Vec<syn::Type>,
+    error: Option<Path>,
+    /// `error(generate)`: emit `<Target>ConversionError` with one variant per fallible field.
+    generate_error: bool,
+    /// `DtoFrom` with `fallible`: generate `TryFrom`, with transforms returning `Result` and `into`
+    /// becoming `try_into`.
+    fallible: bool,
+    by_ref: bool,
+    /// The `from` types are `Arc<Source>` / `Rc<Source>`: the impl takes the pointer and maps from
+    /// the pointee like `by_ref`.
+    shared: bool,
+    /// Generate both the owned and the `by_ref` impl.
+    both_refs: bool,
+    /// Extra `where` predicates from `bound = "..."` (or `where = "..."`), merged into every generated impl.
+    bounds: Vec<syn::WherePredicate>,
+    /// Case convention of the source field names, from `rename_all = "..."`.
+    rename_all: Option<RenameRule>,
+    /// Prefix the source puts on every field name, from `strip_prefix = "user_"`.
+    strip_prefix: Option<String>,
+    /// `DtoInto` only: fill source fields the DTO does not carry with `..Default::default()`.
+    reverse_default: bool,
+    /// Span of `fill_default`: skipped fields are left out and filled by the target's
+    /// `..Default::default()`, so only the target needs `Default`, not each skipped field.
+    fill_default: Option<Span>,
+    /// Only annotated fields are mapped: unannotated fields are an error, or under
+    /// `fill_default` are left to the target's `Default`.
+    only_annotated: bool,
+    /// Leave `#[inline]` off the generated conversion functions.
+    no_inline: bool,
+    /// Also emit `from_vec` and `map_all` (or `try_from_vec` / `try_map_all`) associated
+    /// functions on the target.
+    collection_helper: bool,
+    /// Also emit `Target::map_from(source)` (or `try_map_from`) delegating to the derived impl.
+    inherent: bool,
+    /// Span of `const_fn`: also emit `Target::from_const(source)` as a `const fn`.
+    const_fn: Option<Span>,
+    /// `post_build = path`: called as `path(&mut target, &source)` once every field is assigned.
+    post_build: Option<Path>,
+    /// `debug_assert_invariant = "self.start <= self.end"`: the parsed check, with `self` already
+    /// naming the constructed value, and the literal it came from.
+    debug_assert_invariant: Option<(proc_macro2::TokenStream, syn::LitStr)>,
+    /// Span of the `exhaustive` key: every source field must be read by some DTO field.
+    exhaustive: Option<Span>,
+    /// Direct fields go through `Into`, so nested DTOs convert without a per-field `into`.
+    auto_nested: bool,
+    /// Span of `generics_from_source`: type parameters named by `from` are declared on the impl.
+    generics_from_source: Option<Span>,
+    /// Print the generated code to stderr at compile time.
+    debug: bool,
+    /// `cfg_feature = "serde"`: the generated items are compiled only with that cargo feature.
+    cfg_feature: Option<syn::LitStr>,
+}
+
 #[derive(Default)]
 struct FieldAttrs {
+    /// The field's `#[cfg(..)]` attributes, repeated on everything generated for it so a
+    /// conditionally compiled field is mapped only when present. `cfg_attr` is already expanded
+    /// by the time a derive sees the field.
+    cfg_attrs: Vec<Attribute>,
+    /// Span of `direct`: an explicit marker for the default name-for-name mapping.
+    direct: Option<Span>,
     rename: Option<Ident>,
     rename_span: Option<Span>,
-    transform_fn: Option<Path>,
+    source_path: Option<Vec<syn::Member>>,
+    source_path_span: Option<Span>,
+    /// Positional source field from `index = N`, for tuple-struct sources.
+    index: Option<syn::Index>,
+    /// Map field from `from_map = "attrs"`, read as `source.attrs.get(key)` for a single entry.
+    from_map: Option<Ident>,
+    /// Entry looked up by `from_map`, from `key = "..."`; defaults to the DTO field name.
+    map_key: Option<syn::LitStr>,
+    /// Callee from `transform_fn = ...`: usually a function path, but any expression that evaluates
+    /// to something callable (`FORMATTERS[0]`, `registry().slug`) is accepted.
+    transform_fn: Option<syn::Expr>,
+    /// Extra arguments from `args(..)`, appended after the source value in the transform call.
+    args: Option<Vec<syn::Expr>>,
+    args_span: Option<Span>,
+    /// Source fields from `with_fields("first", "last")`, passed to the transform in place of
+    /// the field's own value.
+    with_fields: Option<Vec<Ident>>,
+    with_fields_span: Option<Span>,
+    /// Span of `wrap_some`: the transform returns `T` for an `Option<T>` field, wrapped in `Some`.
+    wrap_some: Option<Span>,
+    /// Span of `flatten_some`: the transform returns `Option<T>` for a `T` field, unwrapped with `expect`.
+    flatten_some: Option<Span>,
+    /// Span of `then_into`: the transform's result goes through `Into::into` to reach the field type.
+    then_into: Option<Span>,
+    /// Span of `or_default`: a `Result` / `Option` returned by the transform falls back to
+    /// `unwrap_or_default()`.
+    or_default: Option<Span>,
+    /// Span of `element_into`: the transform's collection is rebuilt with `Into` on each element.
+    element_into: Option<Span>,
+    /// Inline closure from `transform_expr = |x| ...`, applied like `transform_fn`.
+    transform_expr: Option<syn::ExprClosure>,
+    /// In-place normalizer from `transform_mut = path`, called as `path(&mut value)`.
+    transform_mut: Option<Path>,
+    /// Conversion module from `with = path`: `path::from_source` forward, `path::to_source` back.
+    with: Option<Path>,
+    try_transform_fn: Option<syn::ExprPath>,
+    /// `map_err = path`: the transform's error goes through `path` before `?`.
+    map_err: Option<Path>,
+    reverse_transform_fn: Option<syn::ExprPath>,
+    from_source: bool,
+    /// Read the source through a method call (`source.name()`) instead of a field.
+    call: bool,
+    /// Clone the source value instead of moving it.
+    clone: bool,
+    /// Pass the transform `&source.field` instead of the moved value (`ref`).
+    ref_arg: bool,
+    /// Span of `opt_ref`: map the transform over `source.field.as_ref()`, so it receives `&T`.
+    opt_ref: Option<Span>,
+    /// Dereference a `Box<T>` source value before converting it.
+    unbox: bool,
+    /// Wrap the converted value in `Box::new(..)`.
+    box_flag: bool,
+    /// Trait from `boxed_dyn = Trait`: the boxed value is cast to `Box<dyn Trait>`. Sets `box_flag`.
+    boxed_dyn: Option<Path>,
+    /// `arc`, `rc`, `cell` or `refcell`, with the key's span: the converted value goes through
+    /// that type's `new`.
+    wrapper: Option<(Wrapper, Span)>,
+    /// Expected source field type from `assert_type = Type`, checked by a guard statement.
+    assert_type: Option<syn::Type>,
     skip: bool,
+    /// Span of the `skip` key, for errors about the key itself (a bare `skip` under `const_fn`).
+    skip_span: Option<Span>,
+    /// `skip` written as a bare `default`, so diagnostics name the key the user wrote.
+    skip_as_default: bool,
+    default_expr: Option<syn::Expr>,
+    /// Zero-argument constructor from `with_default_fn = path`, called as `path()`.
+    default_fn: Option<Path>,
     into_flag: bool,
+    into_span: Option<Span>,
+    /// Destination pinned by `into = Type`, for conversions inference cannot resolve.
+    into_ty: Option<Path>,
+    /// Intermediate type from `into_via = Type`: two `Into` hops, through `Type` to the field type.
+    into_via: Option<Path>,
+    auto_into: bool,
+    /// Rebuild the collection element-wise with `Into`, collecting into the field type.
+    collect: bool,
+    /// Convert the values of a `HashMap`/`BTreeMap` with `Into`, keeping the keys.
+    map_values: bool,
+    /// Convert both keys and values of a `HashMap`/`BTreeMap` with `Into`.
+    map_keys_values: bool,
+    /// Fallback from `unwrap_or = expr` for an `Option<T>` source mapped to `T`.
+    unwrap_or: Option<syn::Expr>,
+    unwrap_or_default: bool,
+    /// The source is a `Result<T, E>`: `?` when fallible, a panic otherwise. With `unwrap_or` /
+    /// `unwrap_or_default`, those fall back on `Err` instead.
+    unwrap_result: bool,
+    /// Wrap the source value in `Cow::Owned` (or `Cow::Borrowed` when mapping by reference).
+    cow: bool,
+    /// Numeric cast target from `as = Type`, emitted as `source.field as Type`.
+    cast: Option<syn::Type>,
+    /// Parse a string source with `FromStr`; `from_str_or_default` falls back to `Default`.
+    from_str: bool,
+    from_str_or_default: bool,
+    /// `chrono` feature: `NaiveDateTime` ↔ `DateTime<Utc>`, element-wise for `Option` fields.
+    naive_to_utc: bool,
+    utc_to_naive: bool,
+    try_into_flag: bool,
+    /// `try_into_expect = "msg"`: `TryInto` in an infallible impl, panicking with `msg` on `Err`.
+    try_into_expect: Option<syn::LitStr>,
+    /// Error type of this field's conversion, naming its variant under `error(generate)`.
+    error_ty: Option<syn::Type>,
+}
+
+/// Collects the distinct lifetimes written anywhere in `tokens`.
+fn collect_lifetimes(tokens: proc_macro2::TokenStream, out: &mut Vec<syn::Lifetime>) {
+    let mut iter = tokens.into_iter().peekable();
+    while let Some(tt) = iter.next() {
+        match tt {
+            proc_macro2::TokenTree::Punct(p)
+                if p.as_char() == '\'' && p.spacing() == proc_macro2::Spacing::Joint =>
+            {
+                if let Some(proc_macro2::TokenTree::Ident(ident)) = iter.next() {
+                    if !out.iter().any(|l| l.ident == ident) {
+                        out.push(syn::Lifetime {
+                            apostrophe: p.span(),
+                            ident,
+                        });
+                    }
+                }
+            }
+            proc_macro2::TokenTree::Group(g) => collect_lifetimes(g.stream(), out),
+            _ => {}
+        }
+    }
+}
+
+/// Whether `tokens` name any of `idents`, at any depth.
+fn mentions_ident(tokens: proc_macro2::TokenStream, idents: &[Ident]) -> bool {
+    tokens.into_iter().any(|tree| match tree {
+        proc_macro2::TokenTree::Ident(ident) => idents.contains(&ident),
+        proc_macro2::TokenTree::Group(group) => mentions_ident(group.stream(), idents),
+        _ => false,
+    })
+}
+
+/// Collects the bare identifiers used as type arguments in `ty` (`T` in `Page<Vec<T>>`), the
+/// parameters `generics_from_source` declares on the impl.
+fn collect_type_params(ty: &syn::Type, out: &mut Vec<Ident>) {
+    match ty {
+        syn::Type::Reference(r) => collect_type_params(&r.elem, out),
+        syn::Type::Paren(p) => collect_type_params(&p.elem, out),
+        syn::Type::Slice(s) => collect_type_params(&s.elem, out),
+        syn::Type::Array(a) => collect_type_params(&a.elem, out),
+        syn::Type::Tuple(t) => t
+            .elems
+            .iter()
+            .for_each(|elem| collect_type_params(elem, out)),
+        syn::Type::Path(p) => {
+            for segment in &p.path.segments {
+                let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                    continue;
+                };
+                for arg in &args.args {
+                    let syn::GenericArgument::Type(inner) = arg else {
+                        continue;
+                    };
+                    match inner {
+                        syn::Type::Path(param)
+                            if param.qself.is_none()
+                                && param.path.leading_colon.is_none()
+                                && param.path.segments.len() == 1
+                                && param.path.segments[0].arguments.is_none() =>
+                        {
+                            let ident = &param.path.segments[0].ident;
+                            if !out.contains(ident) {
+                                out.push(ident.clone());
+                            }
+                        }
+                        _ => collect_type_params(inner, out),
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `ty` as written for a message, without the token spacing of `to_string` (`types :: Page < u8 >`).
+fn type_display(ty: &syn::Type) -> String {
+    let mut out = quote!(#ty).to_string();
+    for (spaced, tight) in [
+        (" :: ", "::"),
+        (":: ", "::"),
+        (" <", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        ("& ", "&"),
+    ] {
+        out = out.replace(spaced, tight);
+    }
+    out
+}
+
+/// A function path, or a qualified one like `<Celsius as Into<f64>>::into`. Plain paths keep
+/// type-style generics (`vec_into<A, B>`) rather than requiring a turbofish.
+fn parse_fn_path(input: syn::parse::ParseStream) -> syn::Result<syn::ExprPath> {
+    if input.peek(syn::Token![<]) {
+        input.parse()
+    } else {
+        Ok(syn::ExprPath {
+            attrs: Vec::new(),
+            qself: None,
+            path: input.parse()?,
+        })
+    }
+}
+
+/// `module::name`, for the functions a `with = module` field calls.
+fn module_fn(module: &Path, name: &str) -> Path {
+    let mut path = module.clone();
+    path.segments.push(Ident::new(name, module.span()).into());
+    path
 }
 
 enum FieldAction {
-    Skip,
-    Transform(Path),
+    Skip(Option<syn::Expr>),
+    Transform(syn::Expr),
+    TransformExpr(syn::ExprClosure),
+    TransformMut(Path),
+    TryTransform(syn::ExprPath),
     Into,
+    IntoVia(Path),
+    AutoInto,
+    Collect,
+    MapValues,
+    MapKeysValues,
+    UnwrapOr(syn::Expr),
+    UnwrapOrDefault,
+    UnwrapResult,
+    Cow,
+    Cast(syn::Type),
+    /// `chrono` conversion; `true` is `naive_to_utc`, `false` is `utc_to_naive`.
+    Chrono(bool),
+    /// `FromStr` parse; `true` falls back to `Default` instead of failing.
+    FromStr(bool),
+    TryInto,
+    /// `TryInto` unwrapped with `expect`, for conversions that cannot fail in practice.
+    TryIntoExpect(syn::LitStr),
     Direct,
 }
 
 fn decide_action(a: &FieldAttrs) -> FieldAction {
-    if a.skip {
-        FieldAction::Skip
+    if let Some(ref f) = a.default_fn {
+        FieldAction::Skip(Some(syn::parse_quote_spanned! { f.span() => #f() }))
+    } else if a.skip || a.default_expr.is_some() {
+        FieldAction::Skip(a.default_expr.clone())
     } else if let Some(ref f) = a.transform_fn {
         FieldAction::Transform(f.clone())
+    } else if let Some(ref f) = a.transform_expr {
+        FieldAction::TransformExpr(f.clone())
+    } else if let Some(ref f) = a.transform_mut {
+        FieldAction::TransformMut(f.clone())
+    } else if let Some(ref module) = a.with {
+        let path = module_fn(module, "from_source");
+        FieldAction::Transform(syn::parse_quote! { #path })
+    } else if let Some(ref f) = a.try_transform_fn {
+        FieldAction::TryTransform(f.clone())
     } else if a.into_flag {
         FieldAction::Into
+    } else if let Some(ref via) = a.into_via {
+        FieldAction::IntoVia(via.clone())
+    } else if a.auto_into {
+        FieldAction::AutoInto
+    } else if a.collect {
+        FieldAction::Collect
+    } else if a.map_values {
+        FieldAction::MapValues
+    } else if a.map_keys_values {
+        FieldAction::MapKeysValues
+    } else if let Some(ref fallback) = a.unwrap_or {
+        FieldAction::UnwrapOr(fallback.clone())
+    } else if a.unwrap_or_default {
+        FieldAction::UnwrapOrDefault
+    } else if a.unwrap_result {
+        FieldAction::UnwrapResult
+    } else if a.cow {
+        FieldAction::Cow
+    } else if let Some(ref ty) = a.cast {
+        FieldAction::Cast(ty.clone())
+    } else if a.naive_to_utc || a.utc_to_naive {
+        FieldAction::Chrono(a.naive_to_utc)
+    } else if a.from_str || a.from_str_or_default {
+        FieldAction::FromStr(a.from_str_or_default)
+    } else if a.try_into_flag {
+        FieldAction::TryInto
+    } else if let Some(ref message) = a.try_into_expect {
+        FieldAction::TryIntoExpect(message.clone())
     } else {
         FieldAction::Direct
     }
@@ -247,181 +1348,3282 @@ fn decide_action(a: &FieldAttrs) -> FieldAction {
 #[proc_macro_derive(DtoFrom, attributes(dto))]
 pub fn dto_from_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let target_struct = &input.ident;
+    expand(&input, ImplKind::From)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(DtoTryFrom, attributes(dto))]
+pub fn dto_try_from_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, ImplKind::TryFrom)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    let source_ty = match find_source_type(&input.attrs) {
-        Ok(path) => path,
-        Err(e) => return e.to_compile_error().into(),
+#[proc_macro_derive(DtoInto, attributes(dto))]
+pub fn dto_into_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, ImplKind::Into)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput, kind: ImplKind) -> syn::Result<proc_macro2::TokenStream> {
+    let target_struct = &input.ident;
+    let mut struct_attrs = extract_dto_struct_attrs(&input.attrs, kind)?;
+    if struct_attrs.generate_error {
+        struct_attrs.error = Some(quote::format_ident!("{}ConversionError", target_struct).into());
+    }
+    // `fallible` turns `DtoFrom` into the `DtoTryFrom` expansion.
+    let kind = if kind == ImplKind::From && struct_attrs.fallible {
+        ImplKind::TryFrom
+    } else {
+        kind
     };
 
-    let generics = input.generics.clone();
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut generics = input.generics.clone();
+    if !struct_attrs.bounds.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(struct_attrs.bounds.iter().cloned());
+    }
+    // Lifetimes named only by the source type (`from = &'a Source`) are declared on the impl.
+    let declared: Vec<Ident> = input
+        .generics
+        .lifetimes()
+        .map(|l| l.lifetime.ident.clone())
+        .collect();
+    let mut extra_lifetimes: Vec<syn::Lifetime> = Vec::new();
+    for source in &struct_attrs.sources {
+        collect_lifetimes(quote!(#source), &mut extra_lifetimes);
+    }
+    extra_lifetimes
+        .retain(|l| l.ident != "static" && l.ident != "_" && !declared.contains(&l.ident));
+    for (i, lifetime) in extra_lifetimes.into_iter().enumerate() {
+        generics.params.insert(
+            i,
+            syn::GenericParam::Lifetime(syn::LifetimeParam::new(lifetime)),
+        );
+    }
+    // The inherent helper impl cannot name parameters the target does not declare.
+    let mut helper_generics = generics.clone();
+    if let Some(key_span) = struct_attrs.generics_from_source {
+        let declared: Vec<&Ident> = input.generics.type_params().map(|p| &p.ident).collect();
+        let mut pulled: Vec<Ident> = Vec::new();
+        for source in &struct_attrs.sources {
+            collect_type_params(source, &mut pulled);
+        }
+        if let Some(clash) = input
+            .generics
+            .type_params()
+            .find(|p| pulled.contains(&p.ident))
+        {
+            return Err(syn::Error::new(
+                clash.ident.span(),
+                format!(
+                    "`{}` is declared on the DTO and taken from the source by `generics_from_source`; remove one of them",
+                    clash.ident
+                ),
+            ));
+        }
+        pulled.retain(|ident| !declared.contains(&ident));
+        if pulled.is_empty() {
+            return Err(syn::Error::new(
+                key_span,
+                "`generics_from_source` found no type parameters in `from`; write them as bare arguments like `Page<T>`",
+            ));
+        }
+        // Bounds on the pulled-in parameters belong to the `From` impl only; the helpers reach it
+        // through `Self: From<__S>`.
+        if let Some(clause) = helper_generics.where_clause.as_mut() {
+            clause.predicates = std::mem::take(&mut clause.predicates)
+                .into_iter()
+                .filter(|predicate| !mentions_ident(quote!(#predicate), &pulled))
+                .collect();
+        }
+        let at = generics.lifetimes().count();
+        for (i, ident) in pulled.into_iter().enumerate() {
+            generics
+                .params
+                .insert(at + i, syn::GenericParam::Type(ident.into()));
+        }
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (helper_impl_generics, _, helper_where_clause) = helper_generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    // A DTO with a single lifetime borrows under it, so `by_ref` reads `&'a Source` for `Dto<'a>`.
+    let borrow_lifetime = match input.generics.lifetimes().collect::<Vec<_>>()[..] {
+        [param] => Some(&param.lifetime),
+        _ => None,
+    };
 
-    let fields = match &input.data {
+    // A single-variant enum is filled like a struct, through `Self::Variant { .. }`.
+    let (fields, variant) = match &input.data {
         Data::Struct(s) => match &s.fields {
-            Fields::Named(named) => &named.named,
-            _ => {
-                return syn::Error::new_spanned(
+            Fields::Named(named) => (&named.named, None),
+            Fields::Unnamed(unnamed) => (&unnamed.unnamed, None),
+            Fields::Unit => {
+                return Err(syn::Error::new_spanned(
                     &input.ident,
-                    "DtoFrom only supports named-field structs.",
-                )
-                .to_compile_error()
-                .into();
+                    format!(
+                        "{} only supports structs with named or tuple fields.",
+                        kind.derive_name()
+                    ),
+                ));
             }
         },
-        _ => {
-            return syn::Error::new_spanned(&input.ident, "DtoFrom only supports structs.")
-                .to_compile_error()
-                .into();
+        // Enum *sources* work through `from_source` transforms; only the target must be a struct.
+        Data::Enum(_) if kind == ImplKind::Into => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                format!(
+                    "{} only supports structs: its fields are read as `dto.field`, which an enum does not have",
+                    kind.derive_name()
+                ),
+            ));
         }
-    };
-
-    let field_map = fields.iter().map(|f| {
-        let ident = f.ident.as_ref().expect("named fields guaranteed");
-        let cfg = match extract_dto_field_attrs(&f.attrs) {
-            Ok(c) => c,
-            Err(e) => return e.to_compile_error(),
-        };
-        let src_ident = cfg.rename.clone().unwrap_or_else(|| ident.clone());
-        let access_span = cfg.rename_span.unwrap_or_else(|| ident.span());
-        generate_field_mapping(ident, &src_ident, &cfg, access_span)
-    });
-
-    let owned_impl = quote! {
-        impl #impl_generics From<#source_ty> for #target_struct #ty_generics #where_clause {
-            fn from(source: #source_ty) -> Self {
-                Self { #(#field_map,)* }
+        Data::Enum(e) if e.variants.len() != 1 => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                format!(
+                    "{} only supports structs and single-variant enums: this enum has {} variants; consider mapping each variant manually",
+                    kind.derive_name(),
+                    e.variants.len()
+                ),
+            ));
+        }
+        Data::Enum(e) => match &e.variants[0].fields {
+            Fields::Named(named) => (&named.named, Some(&e.variants[0].ident)),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &e.variants[0].ident,
+                    format!(
+                        "{} maps a single-variant enum through its named fields; give `{}` `{{ .. }}` fields",
+                        kind.derive_name(),
+                        e.variants[0].ident
+                    ),
+                ));
             }
+        },
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                format!(
+                    "{} only supports structs: unions are not supported",
+                    kind.derive_name()
+                ),
+            ));
         }
     };
 
-    TokenStream::from(quote! { #owned_impl })
-}
-
-fn generate_field_mapping(
-    ident: &Ident,
-    source_ident: &Ident,
-    a: &FieldAttrs,
-    access_span: Span,
-) -> proc_macro2::TokenStream {
-    match decide_action(a) {
-        FieldAction::Skip => {
-            quote! { #ident: Default::default() }
-        }
-        FieldAction::Transform(ref f) => {
-            quote_spanned! { access_span => #ident: #f(source.#source_ident) }
-        }
-        FieldAction::Into => {
-            quote_spanned! { access_span => #ident: ::core::convert::Into::into(source.#source_ident) }
-        }
-        FieldAction::Direct => {
-            quote_spanned! { access_span => #ident: source.#source_ident }
-        }
+    if let (Some(span), Some(_)) = (struct_attrs.fill_default, variant) {
+        return Err(syn::Error::new(
+            span,
+            "`fill_default` needs a struct target: an enum variant cannot be completed with `..Default::default()`",
+        ));
     }
-}
-
-fn extract_dto_field_attrs(attrs: &[Attribute]) -> syn::Result<FieldAttrs> {
-    let mut cfg = FieldAttrs::default();
-    let mut seen_rename = false;
-    let mut seen_transform = false;
-    let mut seen_skip = false;
-    let mut seen_into = false;
 
-    for attr in attrs {
-        if !attr.path().is_ident("dto") {
-            continue;
-        }
-        attr.parse_nested_meta(|meta| {
-            if meta.path.is_ident("rename") {
-                let lit = meta.value()?.parse::<syn::LitStr>()?;
-                if lit.value().trim().is_empty() {
-                    return Err(syn::Error::new(lit.span(), "`rename` cannot be empty"));
-                }
-                if seen_rename {
-                    return Err(syn::Error::new(lit.span(), "duplicate `rename`"));
+    // Collect every field's diagnostics instead of stopping at the first one.
+    let mut errors: Option<syn::Error> = None;
+    let mut push_error = |e: syn::Error| match errors {
+        Some(ref mut acc) => acc.combine(e),
+        None => errors = Some(e),
+    };
+    let mut parsed = Vec::with_capacity(fields.len());
+    for (index, f) in fields.iter().enumerate() {
+        // Tuple fields are initialized as `Self { 0: .., 1: .. }`, which lets positional and
+        // named targets share every mapping action.
+        let member = match &f.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index {
+                index: index as u32,
+                span: f.ty.span(),
+            }),
+        };
+        let checked = extract_dto_field_attrs(&f.attrs, kind, struct_attrs.fallible).and_then(|mut cfg| {
+            if struct_attrs.only_annotated && !f.attrs.iter().any(|a| a.path().is_ident("dto")) {
+                // With `fill_default` as well, an unannotated field is the target's default.
+                if struct_attrs.fill_default.is_some() {
+                    cfg.skip = true;
+                    return Ok(cfg);
                 }
-                seen_rename = true;
-                cfg.rename_span = Some(lit.span());
-                cfg.rename = Some(Ident::new(&lit.value(), lit.span()));
-            } else if meta.path.is_ident("transform_fn") {
-                if seen_transform {
+                return Err(syn::Error::new(
+                    member.span(),
+                    "with `only_annotated`, every field needs a `#[dto(...)]` attribute; use `#[dto(direct)]` to map it by name",
+                ));
+            }
+            if let (Some(map), None) = (&cfg.from_map, &cfg.map_key) {
+                // the entry is keyed by the DTO field's own name unless `key` says otherwise
+                let syn::Member::Named(ident) = &member else {
                     return Err(syn::Error::new(
-                        meta.path.span(),
-                        "duplicate `transform_fn`",
+                        map.span(),
+                        "`from_map` on a tuple field needs `key = \"...\"` to name the entry",
+                    ));
+                };
+                let name = syn::ext::IdentExt::unraw(ident).to_string();
+                cfg.map_key = Some(syn::LitStr::new(&name, map.span()));
+            }
+            if (cfg.map_values || cfg.map_keys_values) && container_of(&f.ty) != Container::Map {
+                let key = if cfg.map_values {
+                    "map_values"
+                } else {
+                    "map_keys_values"
+                };
+                return Err(syn::Error::new_spanned(
+                    &f.ty,
+                    format!("`{key}` requires a `HashMap<K, V>` or `BTreeMap<K, V>` field type"),
+                ));
+            }
+            if cfg.cow && container_of(&f.ty) != Container::Cow {
+                return Err(syn::Error::new_spanned(
+                    &f.ty,
+                    "`cow` requires a `Cow<'a, B>` field type",
+                ));
+            }
+            if struct_attrs.both_refs {
+                check_both_refs(&cfg, &member, kind)?;
+            }
+            if let (Some(_), ImplKind::From) = (struct_attrs.const_fn, kind) {
+                check_const_fn(&cfg, &f.ty, &member)?;
+            }
+            match (&cfg.error_ty, struct_attrs.generate_error) {
+                (Some(ty), false) => {
+                    return Err(syn::Error::new_spanned(
+                        ty,
+                        "field-level `error` requires `#[dto(error(generate))]` on the struct",
                     ));
                 }
-                seen_transform = true;
-                let val = meta.value()?;
-                cfg.transform_fn = Some(val.parse()?);
-            } else if meta.path.is_ident("skip") {
-                if seen_skip {
-                    return Err(syn::Error::new(meta.path.span(), "duplicate `skip`"));
+                (Some(ty), true) if !can_fail(&cfg, &struct_attrs) => {
+                    return Err(syn::Error::new_spanned(
+                        ty,
+                        "this field's conversion cannot fail; drop its `error`",
+                    ));
                 }
-                seen_skip = true;
-                cfg.skip = true;
-            } else if meta.path.is_ident("into") {
-                if seen_into {
-                    return Err(syn::Error::new(meta.path.span(), "duplicate `into`"));
+                (None, true) if can_fail(&cfg, &struct_attrs) => {
+                    return Err(syn::Error::new(
+                        member.span(),
+                        "with `error(generate)`, every fallible field needs `#[dto(error = Type)]` for its variant",
+                    ));
                 }
-                seen_into = true;
-                cfg.into_flag = true;
-            } else {
-                return Err(syn::Error::new(
-                    meta.path.span(),
-                    "unknown #[dto(...)] key; expected one of: rename, transform_fn, skip, into",
-                ));
+                _ => {}
             }
-            Ok(())
-        })?;
+            Ok(cfg)
+        });
+        match checked {
+            Ok(cfg) => parsed.push((member, f, cfg)),
+            Err(e) => push_error(e),
+        }
     }
 
-    if cfg.skip && (cfg.rename.is_some() || cfg.transform_fn.is_some() || cfg.into_flag) {
-        return Err(syn::Error::new(
-            Span::call_site(),
-            "`#[dto(skip)]` cannot be combined with `rename`, `transform_fn`, or `into`",
-        ));
+    // `both_refs` expands the fields twice: moving out of `Source` and cloning out of `&Source`.
+    let modes: &[bool] = if struct_attrs.both_refs {
+        &[false, true]
+    } else if struct_attrs.by_ref {
+        &[true]
+    } else {
+        &[false]
+    };
+    let mut bodies = Vec::with_capacity(modes.len());
+    // Top-level source fields the mapping reads, for the `exhaustive` pattern.
+    let mut consumed: Vec<syn::Member> = Vec::new();
+    for &by_ref in modes {
+        let st = StructAttrs {
+            by_ref,
+            ..struct_attrs.clone()
+        };
+        let mut field_map = Vec::with_capacity(parsed.len());
+        let mut preludes = Vec::new();
+        consumed.clear();
+        for (member, f, cfg) in &parsed {
+            let src_member = match (&cfg.index, &cfg.rename, member) {
+                (Some(index), _, _) => syn::Member::Unnamed(index.clone()),
+                (None, Some(renamed), _) => syn::Member::Named(renamed.clone()),
+                (None, None, syn::Member::Named(ident))
+                    if st.strip_prefix.is_some() || st.rename_all.is_some() =>
+                {
+                    // the prefix joins the DTO name first, so `rename_all` converts them together
+                    let mut name = syn::ext::IdentExt::unraw(ident).to_string();
+                    if let Some(ref prefix) = st.strip_prefix {
+                        name = format!("{prefix}{name}");
+                    }
+                    if let Some(rule) = st.rename_all {
+                        name = rule.apply(&name);
+                    }
+                    syn::Member::Named(
+                        field_ident(&name, ident.span()).unwrap_or_else(|| ident.clone()),
+                    )
+                }
+                (None, None, _) => member.clone(),
+            };
+            let access_span = cfg
+                .rename_span
+                .or(cfg.source_path_span)
+                .or(cfg.index.as_ref().map(|index| index.span))
+                .unwrap_or_else(|| member.span());
+            if !(cfg.skip
+                || cfg.default_expr.is_some()
+                || cfg.default_fn.is_some()
+                || cfg.from_source
+                || cfg.call)
+            {
+                let reads = match (&cfg.with_fields, &cfg.source_path, &cfg.from_map) {
+                    (Some(fields), _, _) => {
+                        fields.iter().cloned().map(syn::Member::Named).collect()
+                    }
+                    (None, Some(path), _) => vec![path[0].clone()],
+                    (None, None, Some(map)) => vec![syn::Member::Named(map.clone())],
+                    (None, None, None) => vec![src_member.clone()],
+                };
+                for read in reads {
+                    if !consumed.contains(&read) {
+                        consumed.push(read);
+                    }
+                }
+            }
+            // A bare `skip` under `fill_default` is filled by the struct update instead.
+            if kind != ImplKind::Into
+                && st.fill_default.is_some()
+                && cfg.skip
+                && cfg.default_expr.is_none()
+                && cfg.default_fn.is_none()
+            {
+                continue;
+            }
+            let mapping = if kind == ImplKind::Into {
+                generate_reverse_field_mapping(member, &f.ty, &src_member, cfg, access_span, &st)
+                    .map(|init| {
+                        init.map(|init| FieldInit {
+                            prelude: None,
+                            init,
+                        })
+                    })
+            } else {
+                let src_path = match (&cfg.source_path, &cfg.from_map) {
+                    (Some(path), _) => path.clone(),
+                    (None, Some(map)) => vec![syn::Member::Named(map.clone())],
+                    (None, None) => vec![src_member],
+                };
+                Ok(Some(generate_field_mapping(
+                    member,
+                    &f.ty,
+                    &src_path,
+                    cfg,
+                    access_span,
+                    &st,
+                )))
+            };
+            match mapping {
+                Ok(Some(field)) => {
+                    preludes.extend(field.prelude);
+                    field_map.push(field.init);
+                }
+                Ok(None) => {}
+                Err(e) => push_error(e),
+            }
+        }
+        bodies.push((by_ref, preludes, field_map));
     }
-    if cfg.transform_fn.is_some() && cfg.into_flag {
-        return Err(syn::Error::new(
-            Span::call_site(),
-            "`#[dto(transform_fn = ...)]` conflicts with `#[dto(into)]`",
-        ));
+    if let Some(e) = errors {
+        return Err(e);
     }
 
-    Ok(cfg)
-}
-
-fn find_source_type(attrs: &[Attribute]) -> syn::Result<Path> {
-    let mut result: Option<Path> = None;
-    let mut seen_from = false;
-    for attr in attrs {
-        if !attr.path().is_ident("dto") {
+    let inline = if struct_attrs.no_inline {
+        quote! {}
+    } else {
+        quote! { #[inline] }
+    };
+    // `cfg_feature` compiles every generated item only with that feature of the using crate.
+    let gate = match struct_attrs.cfg_feature {
+        Some(ref feature) => quote! { #[cfg(feature = #feature)] },
+        None => quote! {},
+    };
+    // Downstream crates cannot edit the generated code, so its lints are not theirs to fix.
+    let derived = quote! {
+        #gate
+        #[automatically_derived]
+        #[allow(clippy::all, clippy::pedantic)]
+    };
+    let mut impls = Vec::with_capacity(bodies.len() * struct_attrs.sources.len());
+    let mut exhaustive_checks = Vec::with_capacity(struct_attrs.sources.len());
+    if let (Some(span), true) = (struct_attrs.exhaustive, kind != ImplKind::Into) {
+        for source in &struct_attrs.sources {
+            let source = shared_pointee(source).unwrap_or(source);
+            exhaustive_checks.push(exhaustive_check(source, &consumed, span)?);
+        }
+    }
+    let deref = if struct_attrs.shared {
+        quote! { let __dto_source = &*__dto_source; }
+    } else {
+        quote! {}
+    };
+    let ctor = match variant {
+        Some(variant) => quote! { Self::#variant },
+        None => quote! { Self },
+    };
+    let fill = match struct_attrs.fill_default {
+        Some(span) if kind != ImplKind::Into => {
+            quote_spanned! { span => ..::core::default::Default::default() }
+        }
+        _ => quote! {},
+    };
+    for (by_ref, preludes, field_map) in &bodies {
+        // The hook sees the finished target and the source, which by-ref mode already borrows.
+        let construct = match struct_attrs.post_build {
+            Some(ref hook) => {
+                let source_ref = if *by_ref {
+                    quote! { __dto_source }
+                } else {
+                    quote_spanned! { hook.span() => &__dto_source }
+                };
+                quote_spanned! { hook.span() => {
+                    let mut __dto_out = #ctor { #(#field_map,)* #fill };
+                    #hook(&mut __dto_out, #source_ref);
+                    __dto_out
+                } }
+            }
+            None => quote! { #ctor { #(#field_map,)* #fill } },
+        };
+        let construct = match struct_attrs.debug_assert_invariant {
+            Some((ref check, ref lit)) => {
+                let message = format!(
+                    "`{target_struct}` invariant violated: {}",
+                    lit.value().trim()
+                );
+                quote_spanned! { lit.span() => {
+                    let __dto_out = #construct;
+                    ::core::debug_assert!(#check, #message);
+                    __dto_out
+                } }
+            }
+            None => construct,
+        };
+        for (i, source) in struct_attrs.sources.iter().enumerate() {
+            let check = exhaustive_checks.get(i);
+            let source_ty =
+                if *by_ref && !matches!(source, syn::Type::Reference(_)) && !struct_attrs.shared {
+                    quote! { &#borrow_lifetime #source }
+                } else {
+                    quote! { #source }
+                };
+            impls.push(match kind {
+                ImplKind::From => quote! {
+                    #derived
+                    impl #impl_generics From<#source_ty> for #target_struct #ty_generics #where_clause {
+                        #inline
+                        fn from(__dto_source: #source_ty) -> Self {
+                            #deref
+                            #check
+                            #(#preludes)*
+                            #construct
+                        }
+                    }
+                },
+                ImplKind::TryFrom => {
+                    let error_ty = struct_attrs
+                        .error
+                        .as_ref()
+                        .expect("`error` is validated for DtoTryFrom");
+                    quote! {
+                        #derived
+                        impl #impl_generics ::core::convert::TryFrom<#source_ty> for #target_struct #ty_generics #where_clause {
+                            type Error = #error_ty;
+
+                            #inline
+                            fn try_from(__dto_source: #source_ty) -> ::core::result::Result<Self, Self::Error> {
+                                #deref
+                                #check
+                                #(#preludes)*
+                                ::core::result::Result::Ok(#construct)
+                            }
+                        }
+                    }
+                }
+                ImplKind::Into => {
+                    let target_ty = if *by_ref {
+                        quote! { &#target_struct #ty_generics }
+                    } else {
+                        quote! { #target_struct #ty_generics }
+                    };
+                    let rest = if struct_attrs.reverse_default {
+                        quote! { ..::core::default::Default::default() }
+                    } else {
+                        quote! {}
+                    };
+                    quote! {
+                        #derived
+                        impl #impl_generics From<#target_ty> for #source #where_clause {
+                            #inline
+                            fn from(dto: #target_ty) -> Self {
+                                Self { #(#field_map,)* #rest }
+                            }
+                        }
+                    }
+                }
+            });
+        }
+    }
+    let mut helper_fns = Vec::new();
+    match kind {
+        ImplKind::From => {
+            if struct_attrs.inherent {
+                helper_fns.push(quote! {
+                    /// Converts `source` with the derived `From` impl.
+                    pub fn map_from<__S>(__dto_source: __S) -> Self
+                    where
+                        Self: From<__S>,
+                    {
+                        <Self as From<__S>>::from(__dto_source)
+                    }
+                });
+            }
+            if struct_attrs.const_fn.is_some() {
+                let (_, preludes, field_map) = &bodies[0];
+                let source = &struct_attrs.sources[0];
+                helper_fns.push(quote! {
+                    /// Converts `source` like the derived `From` impl, in `const` contexts too.
+                    pub const fn from_const(__dto_source: #source) -> Self {
+                        #(#preludes)*
+                        #ctor { #(#field_map,)* }
+                    }
+                });
+            }
+            if struct_attrs.collection_helper {
+                helper_fns.push(quote! {
+                    /// Converts every element with the derived `From` impl.
+                    pub fn from_vec<__S>(v: __dto_alloc::vec::Vec<__S>) -> __dto_alloc::vec::Vec<Self>
+                    where
+                        Self: From<__S>,
+                    {
+                        ::core::iter::Iterator::collect(::core::iter::Iterator::map(
+                            ::core::iter::IntoIterator::into_iter(v),
+                            <Self as From<__S>>::from,
+                        ))
+                    }
+
+                    /// Converts every item of any iterable (an array, `Vec`, iterator chain, ...)
+                    /// with the derived `From` impl.
+                    pub fn map_all<__I>(iter: __I) -> __dto_alloc::vec::Vec<Self>
+                    where
+                        __I: ::core::iter::IntoIterator,
+                        Self: From<__I::Item>,
+                    {
+                        ::core::iter::Iterator::collect(::core::iter::Iterator::map(
+                            ::core::iter::IntoIterator::into_iter(iter),
+                            <Self as From<__I::Item>>::from,
+                        ))
+                    }
+                });
+            }
+        }
+        ImplKind::TryFrom => {
+            if struct_attrs.inherent {
+                helper_fns.push(quote! {
+                    /// Converts `source` with the derived `TryFrom` impl.
+                    pub fn try_map_from<__S>(
+                        __dto_source: __S,
+                    ) -> ::core::result::Result<Self, <Self as ::core::convert::TryFrom<__S>>::Error>
+                    where
+                        Self: ::core::convert::TryFrom<__S>,
+                    {
+                        <Self as ::core::convert::TryFrom<__S>>::try_from(__dto_source)
+                    }
+                });
+            }
+            if struct_attrs.collection_helper {
+                helper_fns.push(quote! {
+                    /// Converts every element with the derived `TryFrom` impl, stopping at the first error.
+                    pub fn try_from_vec<__S>(
+                        v: __dto_alloc::vec::Vec<__S>,
+                    ) -> ::core::result::Result<
+                        __dto_alloc::vec::Vec<Self>,
+                        <Self as ::core::convert::TryFrom<__S>>::Error,
+                    >
+                    where
+                        Self: ::core::convert::TryFrom<__S>,
+                    {
+                        ::core::iter::Iterator::collect(::core::iter::Iterator::map(
+                            ::core::iter::IntoIterator::into_iter(v),
+                            <Self as ::core::convert::TryFrom<__S>>::try_from,
+                        ))
+                    }
+
+                    /// Converts every item of any iterable with the derived `TryFrom` impl,
+                    /// stopping at the first error.
+                    pub fn try_map_all<__I>(
+                        iter: __I,
+                    ) -> ::core::result::Result<
+                        __dto_alloc::vec::Vec<Self>,
+                        <Self as ::core::convert::TryFrom<__I::Item>>::Error,
+                    >
+                    where
+                        __I: ::core::iter::IntoIterator,
+                        Self: ::core::convert::TryFrom<__I::Item>,
+                    {
+                        ::core::iter::Iterator::collect(::core::iter::Iterator::map(
+                            ::core::iter::IntoIterator::into_iter(iter),
+                            <Self as ::core::convert::TryFrom<__I::Item>>::try_from,
+                        ))
+                    }
+                });
+            }
+        }
+        ImplKind::Into => {}
+    }
+    let helper = if helper_fns.is_empty() {
+        quote! {}
+    } else if struct_attrs.collection_helper {
+        // the `Vec` in the signatures needs `alloc` in scope, so the impl sits in an anonymous const
+        quote! {
+            #gate
+            const _: () = {
+                extern crate alloc as __dto_alloc;
+
+                #derived
+                impl #helper_impl_generics #target_struct #ty_generics #helper_where_clause {
+                    #(#helper_fns)*
+                }
+            };
+        }
+    } else {
+        quote! {
+            #derived
+            impl #helper_impl_generics #target_struct #ty_generics #helper_where_clause {
+                #(#helper_fns)*
+            }
+        }
+    };
+    let error_enum = match (&struct_attrs.error, kind) {
+        (Some(error_enum), ImplKind::TryFrom) if struct_attrs.generate_error => {
+            let vis = &input.vis;
+            let variants = parsed.iter().filter_map(|(member, _, cfg)| {
+                let error_ty = cfg.error_ty.as_ref()?;
+                let variant = error_variant(member);
+                Some(quote! { #variant(#error_ty) })
+            });
+            let doc =
+                format!("The field of [`{target_struct}`] that failed to convert, with its error.");
+            quote! {
+                #gate
+                #[doc = #doc]
+                #[derive(Debug)]
+                #vis enum #error_enum {
+                    #(#variants,)*
+                }
+            }
+        }
+        _ => quote! {},
+    };
+    let generated = quote! { #error_enum #(#impls)* #helper };
+    if struct_attrs.debug {
+        eprintln!(
+            "{} expansion for `{}`:\n{}",
+            kind.derive_name(),
+            target_struct,
+            generated
+        );
+    }
+
+    Ok(generated)
+}
+
+/// Rejects a field `from_const` cannot initialize: anything but a move, an `as` cast, or a
+/// `default = expr` / `with_default_fn` skip needs a non-`const` call.
+fn check_const_fn(a: &FieldAttrs, ty: &syn::Type, member: &syn::Member) -> syn::Result<()> {
+    let skip_key = if a.skip_as_default { "default" } else { "skip" };
+    if a.skip && a.default_expr.is_none() && a.default_fn.is_none() {
+        if container_of(ty) == Container::Option {
+            return Ok(());
+        }
+        return Err(syn::Error::new(
+            a.skip_span.unwrap_or_else(|| member.span()),
+            format!("a bare `{skip_key}` under `const_fn` calls `Default::default()`, which is not `const`; give it `default = expr`"),
+        ));
+    }
+    let blocker = [
+        (a.call, "call"),
+        (a.clone, "clone"),
+        (a.from_map.is_some(), "from_map"),
+        (a.unbox, "unbox"),
+        (a.boxed_dyn.is_some(), "boxed_dyn"),
+        (a.box_flag, "box"),
+        (a.wrapper.is_some(), a.wrapper.map_or("", |(w, _)| w.key())),
+        (a.flatten_some.is_some(), "flatten_some"),
+        (a.transform_fn.is_some(), "transform_fn"),
+        (a.transform_expr.is_some(), "transform_expr"),
+        (a.transform_mut.is_some(), "transform_mut"),
+        (a.with.is_some(), "with"),
+        (a.into_flag, "into"),
+        (a.into_via.is_some(), "into_via"),
+        (a.auto_into, "auto_into"),
+        (a.collect, "collect"),
+        (a.map_values, "map_values"),
+        (a.map_keys_values, "map_keys_values"),
+        (a.unwrap_or.is_some(), "unwrap_or"),
+        (a.unwrap_or_default, "unwrap_or_default"),
+        (a.unwrap_result, "unwrap_result"),
+        (a.cow, "cow"),
+        (a.from_str, "from_str"),
+        (a.from_str_or_default, "from_str_or_default"),
+        (a.naive_to_utc, "naive_to_utc"),
+        (a.utc_to_naive, "utc_to_naive"),
+        (a.try_into_expect.is_some(), "try_into_expect"),
+    ]
+    .into_iter()
+    .find_map(|(set, key)| set.then_some(key));
+    match blocker {
+        Some(key) => Err(syn::Error::new(
+            member.span(),
+            format!("`{key}` is not `const`-compatible, so `const_fn` cannot generate `from_const`; only direct fields, `as` casts and `default = expr` skips are allowed"),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Rejects field conversions whose argument type differs between the owned and by-ref impls.
+fn check_both_refs(a: &FieldAttrs, member: &syn::Member, kind: ImplKind) -> syn::Result<()> {
+    let key = if a.from_source {
+        // `&Source` in both impls
+        None
+    } else if kind == ImplKind::Into && a.reverse_transform_fn.is_some() {
+        Some("reverse_transform_fn")
+    } else if a.transform_fn.is_some() {
+        Some("transform_fn")
+    } else if a.try_transform_fn.is_some() {
+        Some("try_transform_fn")
+    } else if a.with.is_some() {
+        Some("with")
+    } else {
+        None
+    };
+    match key {
+        Some(key) => Err(syn::Error::new(
+            member.span(),
+            format!(
+                "`{key}` cannot serve both impls of `both_refs`: the owned impl passes `T` and the by-ref impl `&T`; use `transform_expr` or `from_source` instead"
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Generated code for one target field.
+struct FieldInit {
+    /// Statement evaluated before the struct literal. Whole-source transforms run here so they
+    /// borrow `source` before other fields move out of it.
+    prelude: Option<proc_macro2::TokenStream>,
+    /// The `member: expr` entry of the struct literal.
+    init: proc_macro2::TokenStream,
+}
+
+fn generate_field_mapping(
+    member: &syn::Member,
+    ty: &syn::Type,
+    source_path: &[syn::Member],
+    a: &FieldAttrs,
+    access_span: Span,
+    st: &StructAttrs,
+) -> FieldInit {
+    let args = a.args.as_deref().unwrap_or_default();
+    let expr = if a.from_source {
+        let whole = if st.by_ref {
+            quote! { __dto_source }
+        } else {
+            quote! { &__dto_source }
+        };
+        let question = fallible_suffix(st, a, access_span);
+        match (&a.transform_fn, &a.transform_expr, &a.try_transform_fn) {
+            (Some(f), _, _) => {
+                let call = callee(f);
+                quote_spanned! { f.span() => #call(#whole #(, #args)*) #question }
+            }
+            (_, Some(f), _) => {
+                let call = apply_closure(f, whole, access_span);
+                quote! { #call #question }
+            }
+            (_, _, Some(f)) => {
+                let question = try_suffix(a, access_span);
+                quote_spanned! { f.span() => #f(#whole #(, #args)*) #question }
+            }
+            _ => unreachable!("`from_source` is validated to have a transform"),
+        }
+    } else {
+        generate_field_expr(ty, source_path, a, access_span, st)
+    };
+    let expr = match a.or_default {
+        Some(span) => quote_spanned! { span => (#expr).unwrap_or_default() },
+        None => expr,
+    };
+    let expr = match (a.then_into, a.element_into) {
+        (Some(span), _) => quote_spanned! { span => ::core::convert::Into::into(#expr) },
+        (None, Some(span)) => collect_with_into(expr, span),
+        (None, None) => expr,
+    };
+    let expr = match (a.wrap_some, a.flatten_some) {
+        (Some(span), _) => quote_spanned! { span => ::core::option::Option::Some(#expr) },
+        (None, Some(span)) => {
+            let message = format!(
+                "the transform for `{}` returned `None` (`flatten_some`)",
+                quote!(#member)
+            );
+            quote_spanned! { span => ::core::option::Option::expect(#expr, #message) }
+        }
+        (None, None) => expr,
+    };
+    // A path transform's result is bound at the field's type, so a wrong return type is reported
+    // against the field rather than somewhere in the struct literal.
+    let path_transform =
+        a.transform_fn.is_some() || a.with.is_some() || a.try_transform_fn.is_some();
+    let wrapped = a.box_flag || a.wrapper.is_some();
+    let expr = if path_transform && !wrapped {
+        quote_spanned! { ty.span() => {
+            let __dto_value: #ty = #expr;
+            __dto_value
+        } }
+    } else {
+        expr
+    };
+    let expr = match (a.box_flag, &a.boxed_dyn) {
+        // the cast is what coerces the concrete box into the trait object
+        (true, Some(tr)) => with_alloc(
+            access_span,
+            quote_spanned! { tr.span() =>
+                __dto_alloc::boxed::Box::new(#expr) as __dto_alloc::boxed::Box<dyn #tr>
+            },
+        ),
+        (true, None) => with_alloc(
+            access_span,
+            quote_spanned! { access_span => __dto_alloc::boxed::Box::new(#expr) },
+        ),
+        (false, _) => expr,
+    };
+    let expr = match a.wrapper {
+        Some((wrapper, span)) => wrapper.wrap(expr, span),
+        None => expr,
+    };
+    // Under `error(generate)` the conversion's `?` targets the field's own error type, which the
+    // closure's result then tags with the field's variant.
+    let expr = match (&a.error_ty, &st.error) {
+        (Some(error_ty), Some(error_enum)) if st.generate_error => {
+            let variant = error_variant(member);
+            quote_spanned! { access_span =>
+                ::core::result::Result::map_err(
+                    (|| -> ::core::result::Result<_, #error_ty> {
+                        let __dto_value = #expr;
+                        ::core::result::Result::Ok(__dto_value)
+                    })(),
+                    #error_enum::#variant,
+                )?
+            }
+        }
+        _ => expr,
+    };
+
+    let cfg_attrs = &a.cfg_attrs;
+    // The type guard borrows the source, so it runs before any field is moved.
+    let guard = a.assert_type.as_ref().map(|expected| {
+        // Re-span the access too, so a mismatch underlines only the attribute.
+        let span = expected.span();
+        let path = source_path.iter().map(|m| match m {
+            syn::Member::Named(ident) => {
+                let mut ident = ident.clone();
+                ident.set_span(span);
+                syn::Member::Named(ident)
+            }
+            syn::Member::Unnamed(index) => syn::Member::Unnamed(syn::Index {
+                index: index.index,
+                span,
+            }),
+        });
+        let call = if a.call {
+            quote! { () }
+        } else {
+            quote! {}
+        };
+        quote_spanned! { span =>
+            #(#cfg_attrs)*
+            let _: &#expected = &__dto_source #(.#path)* #call;
+        }
+    });
+
+    // Anything that borrows the source (whole-source transforms, getter calls, clones, `ref`
+    // transforms) is bound before the struct literal, while no field has been moved out yet.
+    if a.from_source || a.call || ((a.clone || a.ref_arg || a.opt_ref.is_some()) && !st.by_ref) {
+        let binding = quote::format_ident!(
+            "__dto_field_{}",
+            match member {
+                syn::Member::Named(ident) => syn::ext::IdentExt::unraw(ident).to_string(),
+                syn::Member::Unnamed(index) => index.index.to_string(),
+            },
+            span = access_span
+        );
+        return FieldInit {
+            prelude: Some(quote! { #guard #(#cfg_attrs)* let #binding = #expr; }),
+            init: quote! { #(#cfg_attrs)* #member: #binding },
+        };
+    }
+
+    FieldInit {
+        prelude: guard,
+        init: quote! { #(#cfg_attrs)* #member: #expr },
+    }
+}
+
+fn generate_field_expr(
+    ty: &syn::Type,
+    source_path: &[syn::Member],
+    a: &FieldAttrs,
+    access_span: Span,
+    st: &StructAttrs,
+) -> proc_macro2::TokenStream {
+    // By-ref mode cannot move out of the borrowed source: conversions take a clone and
+    // transforms take a reference instead. Getter results are used as returned.
+    let args = a.args.as_deref().unwrap_or_default();
+    let (owned, transform_arg) = if a.call {
+        let mut call = quote_spanned! { access_span => __dto_source #(.#source_path)* () };
+        if a.unbox {
+            call = quote_spanned! { access_span => (*#call) };
+        }
+        if a.clone {
+            call = quote_spanned! { access_span => ::core::clone::Clone::clone(&#call) };
+        }
+        if a.ref_arg {
+            (call.clone(), quote_spanned! { access_span => &#call })
+        } else {
+            (call.clone(), call)
+        }
+    } else if let Some(ref key) = a.map_key {
+        // The entry is cloned out of the map, so it is owned in every mode.
+        let entry = quote_spanned! { access_span =>
+            __dto_source #(.#source_path)* .get(#key).cloned().unwrap_or_default()
+        };
+        if st.by_ref || a.ref_arg {
+            (entry.clone(), quote_spanned! { access_span => &#entry })
+        } else {
+            (entry.clone(), entry)
+        }
+    } else {
+        let field = if a.unbox {
+            quote_spanned! { access_span => (*__dto_source #(.#source_path)*) }
+        } else {
+            quote_spanned! { access_span => __dto_source #(.#source_path)* }
+        };
+        if a.ref_arg && !st.by_ref {
+            (field.clone(), quote_spanned! { access_span => &#field })
+        } else if a.clone && !st.by_ref {
+            let cloned = quote_spanned! { access_span => ::core::clone::Clone::clone(&#field) };
+            (cloned.clone(), cloned)
+        } else if st.by_ref {
+            (
+                quote_spanned! { access_span => ::core::clone::Clone::clone(&#field) },
+                quote_spanned! { access_span => &#field },
+            )
+        } else {
+            (field.clone(), field)
+        }
+    };
+    // `opt_ref` borrows the option's contents; a by-ref argument is a reference already.
+    let transform_arg = match a.opt_ref {
+        Some(span) if st.by_ref || a.ref_arg || a.map_key.is_some() => {
+            quote_spanned! { span => ::core::option::Option::as_ref(#transform_arg) }
+        }
+        Some(span) => quote_spanned! { span => ::core::option::Option::as_ref(&#transform_arg) },
+        None => transform_arg,
+    };
+    // `with_fields` hands the transform those fields, taken like the field's own value would be.
+    let transform_arg = match (&a.with_fields, a.with_fields_span) {
+        (Some(fields), Some(span)) => {
+            let fields = fields.iter().map(|name| {
+                if st.by_ref || a.ref_arg {
+                    quote_spanned! { span => &__dto_source.#name }
+                } else if a.clone {
+                    quote_spanned! { span => ::core::clone::Clone::clone(&__dto_source.#name) }
+                } else {
+                    quote_spanned! { span => __dto_source.#name }
+                }
+            });
+            quote! { #(#fields),* }
+        }
+        _ => transform_arg,
+    };
+
+    match decide_action(a) {
+        // `None` needs no `Default` on the inner type
+        FieldAction::Skip(None) if container_of(ty) == Container::Option => {
+            quote_spanned! { access_span => ::core::option::Option::None }
+        }
+        // spanned at the field, so a missing `Default` impl underlines the field name
+        FieldAction::Skip(None) => {
+            quote_spanned! { access_span => ::core::default::Default::default() }
+        }
+        FieldAction::Skip(Some(ref expr)) => {
+            quote! { #expr }
+        }
+        FieldAction::Transform(ref f) if a.opt_ref.is_some() => {
+            let call = callee(f);
+            quote_spanned! { f.span() =>
+                ::core::option::Option::map(#transform_arg, |__dto_inner| #call(__dto_inner #(, #args)*))
+            }
+        }
+        FieldAction::Transform(ref f) => {
+            let question = fallible_suffix(st, a, access_span);
+            let call = callee(f);
+            quote_spanned! { f.span() => #call(#transform_arg #(, #args)*) #question }
+        }
+        FieldAction::TransformMut(ref f) => quote_spanned! { f.span() => {
+            let mut __dto_value = #owned;
+            #f(&mut __dto_value);
+            __dto_value
+        } },
+        FieldAction::TransformExpr(ref f) => {
+            let call = apply_closure(f, transform_arg, access_span);
+            let question = fallible_suffix(st, a, access_span);
+            quote! { #call #question }
+        }
+        FieldAction::TryTransform(ref f) => {
+            let question = try_suffix(a, access_span);
+            quote_spanned! { f.span() => #f(#transform_arg #(, #args)*) #question }
+        }
+        FieldAction::Into if st.fallible => match (&a.into_ty, container_of(ty)) {
+            (Some(dest), _) => quote_spanned! { access_span =>
+                <_ as ::core::convert::TryInto<#dest>>::try_into(#owned)?
+            },
+            (None, Container::Vec) => quote_spanned! { access_span =>
+                ::core::iter::Iterator::collect::<::core::result::Result<_, _>>(
+                    ::core::iter::Iterator::map(
+                        ::core::iter::IntoIterator::into_iter(#owned),
+                        ::core::convert::TryInto::try_into,
+                    ),
+                )?
+            },
+            (None, Container::Option) => quote_spanned! { access_span =>
+                ::core::option::Option::transpose(::core::option::Option::map(
+                    #owned,
+                    ::core::convert::TryInto::try_into,
+                ))?
+            },
+            (None, _) => {
+                quote_spanned! { access_span => ::core::convert::TryInto::try_into(#owned)? }
+            }
+        },
+        FieldAction::Into => match a.into_ty {
+            Some(ref dest) => {
+                quote_spanned! { access_span => <_ as ::core::convert::Into<#dest>>::into(#owned) }
+            }
+            // `Option<U>` / `Vec<U>` targets convert the inner values, as no `From` impl exists
+            // between the wrappers themselves.
+            None => element_wise_into(ty, owned, access_span),
+        },
+        FieldAction::IntoVia(ref via) if st.fallible => quote_spanned! { access_span =>
+            ::core::convert::TryInto::try_into(
+                <_ as ::core::convert::TryInto<#via>>::try_into(#owned)?,
+            )?
+        },
+        FieldAction::IntoVia(ref via) => quote_spanned! { access_span =>
+            ::core::convert::Into::into(<_ as ::core::convert::Into<#via>>::into(#owned))
+        },
+        FieldAction::AutoInto => element_wise_into(ty, owned, access_span),
+        FieldAction::Collect => collect_with_into(owned, access_span),
+        FieldAction::MapValues => convert_map_entries(owned, false, access_span),
+        FieldAction::MapKeysValues => convert_map_entries(owned, true, access_span),
+        FieldAction::UnwrapOr(ref fallback) if a.unwrap_result => {
+            quote_spanned! { access_span => ::core::result::Result::unwrap_or(#owned, #fallback) }
+        }
+        FieldAction::UnwrapOr(ref fallback) => {
+            quote_spanned! { access_span => ::core::option::Option::unwrap_or(#owned, #fallback) }
+        }
+        FieldAction::UnwrapOrDefault if a.unwrap_result => {
+            quote_spanned! { access_span => ::core::result::Result::unwrap_or_default(#owned) }
+        }
+        FieldAction::UnwrapOrDefault => {
+            quote_spanned! { access_span => ::core::option::Option::unwrap_or_default(#owned) }
+        }
+        // `DtoTryFrom` or `fallible`: the source's error converts into the struct `error`
+        FieldAction::UnwrapResult if st.error.is_some() => {
+            quote_spanned! { access_span => #owned? }
+        }
+        FieldAction::UnwrapResult => {
+            let message = format!(
+                "`unwrap_result`: `source.{}` is an `Err`",
+                quote!(#(#source_path).*).to_string().replace(' ', "")
+            );
+            quote_spanned! { access_span =>
+                ::core::result::Result::unwrap_or_else(#owned, |_| ::core::panic!(#message))
+            }
+        }
+        // By reference the DTO borrows from the source, which needs a `Cow<'a, _>` tied to `&'a Source`.
+        FieldAction::Cow if st.by_ref && !a.clone && !a.call => with_alloc(
+            access_span,
+            quote_spanned! { access_span =>
+                __dto_alloc::borrow::Cow::Borrowed(::core::borrow::Borrow::borrow(#transform_arg))
+            },
+        ),
+        FieldAction::Cow => with_alloc(
+            access_span,
+            quote_spanned! { access_span => __dto_alloc::borrow::Cow::Owned(#owned) },
+        ),
+        FieldAction::Cast(ref target) => quote_spanned! { access_span => #owned as #target },
+        FieldAction::Chrono(to_utc) => chrono_convert(to_utc, ty, owned, access_span),
+        FieldAction::FromStr(or_default) => {
+            let text = if st.by_ref || a.ref_arg {
+                transform_arg
+            } else {
+                quote_spanned! { access_span => &#transform_arg }
+            };
+            let parsed = quote_spanned! { access_span => ::core::str::FromStr::from_str(#text) };
+            if or_default {
+                quote_spanned! { access_span => ::core::result::Result::unwrap_or_default(#parsed) }
+            } else if st.error.is_some() {
+                // `DtoTryFrom` or `fallible`: the parse error converts into the struct `error`
+                quote_spanned! { access_span => #parsed? }
+            } else {
+                let message = format!(
+                    "`from_str` could not parse `source.{}`",
+                    quote!(#(#source_path).*).to_string().replace(' ', "")
+                );
+                quote_spanned! { access_span =>
+                    ::core::result::Result::unwrap_or_else(#parsed, |_| ::core::panic!(#message))
+                }
+            }
+        }
+        FieldAction::TryInto => {
+            quote_spanned! { access_span => ::core::convert::TryInto::try_into(#owned)? }
+        }
+        FieldAction::TryIntoExpect(ref message) => quote_spanned! { access_span =>
+            ::core::result::Result::expect(::core::convert::TryInto::try_into(#owned), #message)
+        },
+        // The reflexive `From<T> for T` keeps same-typed fields compiling.
+        FieldAction::Direct if st.auto_nested => {
+            quote_spanned! { access_span => ::core::convert::Into::into(#owned) }
+        }
+        // A reference field borrows from the borrowed source rather than cloning it.
+        FieldAction::Direct
+            if st.by_ref
+                && !a.call
+                && a.map_key.is_none()
+                && matches!(ty, syn::Type::Reference(_)) =>
+        {
+            transform_arg
+        }
+        FieldAction::Direct => owned,
+    }
+}
+
+/// Wraps `expr` in a block that names the `alloc` crate `__dto_alloc`. A block-local
+/// `extern crate` resolves in `std` and `#![no_std]` crates alike, where `::std` is missing from the
+/// latter and `::alloc` from a `std` crate's extern prelude.
+fn with_alloc(span: Span, expr: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote_spanned! { span => {
+        extern crate alloc as __dto_alloc;
+        #expr
+    } }
+}
+
+/// The constructor a field's value is wrapped in (`arc`, `rc`, `cell`, `refcell`).
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Wrapper {
+    Arc,
+    Rc,
+    Cell,
+    RefCell,
+}
+
+impl Wrapper {
+    fn from_path(path: &Path) -> Option<Self> {
+        Some(match path.get_ident()?.to_string().as_str() {
+            "arc" => Wrapper::Arc,
+            "rc" => Wrapper::Rc,
+            "cell" => Wrapper::Cell,
+            "refcell" => Wrapper::RefCell,
+            _ => return None,
+        })
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            Wrapper::Arc => "arc",
+            Wrapper::Rc => "rc",
+            Wrapper::Cell => "cell",
+            Wrapper::RefCell => "refcell",
+        }
+    }
+
+    fn wrap(self, expr: proc_macro2::TokenStream, span: Span) -> proc_macro2::TokenStream {
+        match self {
+            Wrapper::Arc => with_alloc(
+                span,
+                quote_spanned! { span => __dto_alloc::sync::Arc::new(#expr) },
+            ),
+            Wrapper::Rc => with_alloc(
+                span,
+                quote_spanned! { span => __dto_alloc::rc::Rc::new(#expr) },
+            ),
+            Wrapper::Cell => quote_spanned! { span => ::core::cell::Cell::new(#expr) },
+            Wrapper::RefCell => quote_spanned! { span => ::core::cell::RefCell::new(#expr) },
+        }
+    }
+
+    /// The inner value of the DTO field for `DtoInto`. By reference it is read in place (and
+    /// cloned by the caller); a pointer that is still shared is cloned out.
+    fn unwrap(
+        self,
+        field: proc_macro2::TokenStream,
+        by_ref: bool,
+        span: Span,
+    ) -> proc_macro2::TokenStream {
+        match (self, by_ref) {
+            (Wrapper::Arc | Wrapper::Rc, true) => quote_spanned! { span => (*#field) },
+            (Wrapper::Arc, false) => with_alloc(
+                span,
+                quote_spanned! { span => __dto_alloc::sync::Arc::unwrap_or_clone(#field) },
+            ),
+            (Wrapper::Rc, false) => with_alloc(
+                span,
+                quote_spanned! { span => __dto_alloc::rc::Rc::unwrap_or_clone(#field) },
+            ),
+            (Wrapper::Cell, true) => quote_spanned! { span => ::core::cell::Cell::get(&#field) },
+            (Wrapper::Cell, false) => {
+                quote_spanned! { span => ::core::cell::Cell::into_inner(#field) }
+            }
+            (Wrapper::RefCell, true) => {
+                quote_spanned! { span => (*::core::cell::RefCell::borrow(&#field)) }
+            }
+            (Wrapper::RefCell, false) => {
+                quote_spanned! { span => ::core::cell::RefCell::into_inner(#field) }
+            }
+        }
+    }
+}
+
+/// `let Source { a: _, b: _ } = source;` without `..`, so the compiler names every source field
+/// the DTO does not read. `_` patterns bind nothing, so the source is not moved.
+fn exhaustive_check(
+    source: &syn::Type,
+    consumed: &[syn::Member],
+    span: Span,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut ty = source;
+    while let syn::Type::Reference(r) = ty {
+        ty = &r.elem;
+    }
+    let mut path = match ty {
+        syn::Type::Path(p) if p.qself.is_none() => p.path.clone(),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                source,
+                "`exhaustive` needs a struct type path in `from`",
+            ))
+        }
+    };
+    // Inferred from `source`, so the pattern does not need the generic arguments. Every token is
+    // re-spanned to the key, so a missing field is reported on `exhaustive` alone.
+    for segment in &mut path.segments {
+        segment.arguments = syn::PathArguments::None;
+        segment.ident.set_span(span);
+    }
+    let members = consumed.iter().map(|m| match m {
+        syn::Member::Named(ident) => {
+            let mut ident = ident.clone();
+            ident.set_span(span);
+            syn::Member::Named(ident)
+        }
+        syn::Member::Unnamed(index) => syn::Member::Unnamed(syn::Index {
+            index: index.index,
+            span,
+        }),
+    });
+    Ok(quote_spanned! { span => let #path { #(#members: _,)* } = __dto_source; })
+}
+
+/// Whether the forward conversion of a field can fail, i.e. ends in `?`.
+fn can_fail(a: &FieldAttrs, st: &StructAttrs) -> bool {
+    match decide_action(a) {
+        FieldAction::TryTransform(_) | FieldAction::TryInto => true,
+        FieldAction::FromStr(or_default) => !or_default,
+        FieldAction::UnwrapResult => true,
+        FieldAction::Transform(_)
+        | FieldAction::TransformExpr(_)
+        | FieldAction::Into
+        | FieldAction::IntoVia(_) => st.fallible,
+        _ => false,
+    }
+}
+
+/// Variant of the generated error enum for a field: `display_name` → `DisplayName`, `0` → `Field0`.
+fn error_variant(member: &syn::Member) -> Ident {
+    match member {
+        syn::Member::Named(ident) => Ident::new(
+            &RenameRule::Pascal.apply(&syn::ext::IdentExt::unraw(ident).to_string()),
+            ident.span(),
+        ),
+        syn::Member::Unnamed(index) => quote::format_ident!("Field{}", index.index),
+    }
+}
+
+/// `?` after a transform call in `fallible` mode, where transforms return `Result`.
+fn fallible_suffix(st: &StructAttrs, a: &FieldAttrs, span: Span) -> proc_macro2::TokenStream {
+    if st.fallible {
+        try_suffix(a, span)
+    } else {
+        quote! {}
+    }
+}
+
+/// `?` after a `Result`-returning transform, converting the error with `map_err` first if set.
+fn try_suffix(a: &FieldAttrs, span: Span) -> proc_macro2::TokenStream {
+    match a.map_err {
+        Some(ref f) => quote_spanned! { f.span() => .map_err(#f)? },
+        None => quote_spanned! { span => ? },
+    }
+}
+
+/// `Into` on the elements of a `Vec<U>` / `Option<U>` field, or on the value itself otherwise.
+fn element_wise_into(
+    ty: &syn::Type,
+    owned: proc_macro2::TokenStream,
+    span: Span,
+) -> proc_macro2::TokenStream {
+    match container_of(ty) {
+        Container::Vec => collect_with_into(owned, span),
+        Container::Option => quote_spanned! { span =>
+            ::core::option::Option::map(#owned, ::core::convert::Into::into)
+        },
+        Container::Map | Container::Cow | Container::Other => quote_spanned! { span =>
+            ::core::convert::Into::into(#owned)
+        },
+    }
+}
+
+/// `Into` on every element of `owned`, collected into whatever `FromIterator` the field type is.
+fn collect_with_into(owned: proc_macro2::TokenStream, span: Span) -> proc_macro2::TokenStream {
+    quote_spanned! { span =>
+        ::core::iter::Iterator::collect(::core::iter::Iterator::map(
+            ::core::iter::IntoIterator::into_iter(#owned),
+            ::core::convert::Into::into,
+        ))
+    }
+}
+
+/// Rebuilds a map with `Into` applied to every value (and every key when `keys` is set).
+fn convert_map_entries(
+    owned: proc_macro2::TokenStream,
+    keys: bool,
+    span: Span,
+) -> proc_macro2::TokenStream {
+    let key = if keys {
+        quote_spanned! { span => ::core::convert::Into::into(k) }
+    } else {
+        quote_spanned! { span => k }
+    };
+    quote_spanned! { span =>
+        ::core::iter::Iterator::collect(::core::iter::Iterator::map(
+            ::core::iter::IntoIterator::into_iter(#owned),
+            |(k, v)| (#key, ::core::convert::Into::into(v)),
+        ))
+    }
+}
+
+/// The callee of a `transform_fn` call: a path as written, any other expression parenthesized so
+/// `(FORMATTERS[0])(value)` calls the element rather than indexing a call.
+fn callee(f: &syn::Expr) -> proc_macro2::TokenStream {
+    match f {
+        syn::Expr::Path(_) => quote! { #f },
+        _ => quote_spanned! { f.span() => (#f) },
+    }
+}
+
+/// Calls an inline `transform_expr` closure on `arg`.
+///
+/// `(|x| ...)(arg)` cannot infer the closure's parameter type, so the argument is passed through a
+/// local helper whose `FnOnce(A)` bound fixes it before the closure body is checked.
+fn apply_closure(
+    closure: &syn::ExprClosure,
+    arg: proc_macro2::TokenStream,
+    span: Span,
+) -> proc_macro2::TokenStream {
+    quote_spanned! { span => {
+        fn __dto_apply<A, R>(arg: A, f: impl ::core::ops::FnOnce(A) -> R) -> R {
+            f(arg)
+        }
+        __dto_apply(#arg, #closure)
+    } }
+}
+
+/// Builds the `DtoInto` initializer for one source field, or `None` when the DTO field is
+/// skipped (it has no source-side counterpart).
+fn generate_reverse_field_mapping(
+    member: &syn::Member,
+    ty: &syn::Type,
+    source_member: &syn::Member,
+    a: &FieldAttrs,
+    access_span: Span,
+    st: &StructAttrs,
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    // `box`/`unbox` swap roles on the way back: a boxed DTO field is dereferenced and an
+    // unboxed one is boxed again for the source. Wrapped values are taken back out.
+    let field = if a.box_flag {
+        quote_spanned! { access_span => (*dto.#member) }
+    } else if let Some((wrapper, span)) = a.wrapper {
+        wrapper.unwrap(
+            quote_spanned! { access_span => dto.#member },
+            st.by_ref,
+            span,
+        )
+    } else {
+        quote_spanned! { access_span => dto.#member }
+    };
+    let (owned, transform_arg) = if st.by_ref {
+        (
+            quote_spanned! { access_span => ::core::clone::Clone::clone(&#field) },
+            quote_spanned! { access_span => &#field },
+        )
+    } else {
+        (field.clone(), field)
+    };
+
+    if a.from_map.is_some() {
+        return Err(syn::Error::new(
+            access_span,
+            "`from_map` reads a single map entry and cannot be reversed by `DtoInto`",
+        ));
+    }
+    if a.source_path.is_some() {
+        return Err(syn::Error::new(
+            access_span,
+            "`source_path` reads a nested field and cannot be reversed by `DtoInto`",
+        ));
+    }
+    if a.call {
+        return Err(syn::Error::new(
+            access_span,
+            "`getter`/`call` reads through a method and cannot be reversed by `DtoInto`",
+        ));
+    }
+    let reverse_fn = match (&a.reverse_transform_fn, &a.with) {
+        (Some(f), _) => Some(quote! { #f }),
+        (None, Some(module)) => {
+            let path = module_fn(module, "to_source");
+            Some(quote! { #path })
+        }
+        (None, None) => None,
+    };
+    let value = match (&reverse_fn, decide_action(a)) {
+        (_, FieldAction::Skip(_)) => return Ok(None),
+        (Some(f), _) => quote_spanned! { f.span() => #f(#transform_arg) },
+        (
+            None,
+            FieldAction::Transform(_)
+            | FieldAction::TransformExpr(_)
+            | FieldAction::TransformMut(_)
+            | FieldAction::TryTransform(_)
+            | FieldAction::TryInto
+            | FieldAction::TryIntoExpect(_),
+        ) => {
+            return Err(syn::Error::new(
+                member.span(),
+                "this field's forward conversion is not invertible; add `reverse_transform_fn = path` for `DtoInto`",
+            ));
+        }
+        (None, FieldAction::Into | FieldAction::AutoInto) => {
+            element_wise_into(ty, owned, access_span)
+        }
+        (None, FieldAction::IntoVia(ref via)) => quote_spanned! { access_span =>
+            ::core::convert::Into::into(<_ as ::core::convert::Into<#via>>::into(#owned))
+        },
+        (None, FieldAction::Collect) => collect_with_into(owned, access_span),
+        (None, FieldAction::MapValues) => convert_map_entries(owned, false, access_span),
+        (None, FieldAction::MapKeysValues) => convert_map_entries(owned, true, access_span),
+        (None, FieldAction::Cow) => with_alloc(
+            access_span,
+            quote_spanned! { access_span => __dto_alloc::borrow::Cow::into_owned(#owned) },
+        ),
+        // the source field's type is not known here, so the cast back is inferred
+        (None, FieldAction::Cast(_)) => quote_spanned! { access_span => #owned as _ },
+        (None, FieldAction::Chrono(to_utc)) => chrono_convert(!to_utc, ty, owned, access_span),
+        (None, FieldAction::FromStr(_)) => with_alloc(
+            access_span,
+            quote_spanned! { access_span => __dto_alloc::string::ToString::to_string(&#owned) },
+        ),
+        (None, FieldAction::UnwrapOr(_) | FieldAction::UnwrapOrDefault) if a.unwrap_result => {
+            quote_spanned! { access_span => ::core::result::Result::Ok(#owned) }
+        }
+        // the fallback is indistinguishable from a real value, so it goes back as `Some`
+        (None, FieldAction::UnwrapOr(_) | FieldAction::UnwrapOrDefault) => {
+            quote_spanned! { access_span => ::core::option::Option::Some(#owned) }
+        }
+        (None, FieldAction::UnwrapResult) => {
+            quote_spanned! { access_span => ::core::result::Result::Ok(#owned) }
+        }
+        (None, FieldAction::Direct) if st.auto_nested => {
+            quote_spanned! { access_span => ::core::convert::Into::into(#owned) }
+        }
+        (None, FieldAction::Direct) => owned,
+    };
+    let value = if a.unbox {
+        with_alloc(
+            access_span,
+            quote_spanned! { access_span => __dto_alloc::boxed::Box::new(#value) },
+        )
+    } else {
+        value
+    };
+    let cfg_attrs = &a.cfg_attrs;
+    Ok(Some(quote! { #(#cfg_attrs)* #source_member: #value }))
+}
+
+/// `fallible` is the struct-level key, which opens the fallible-only keys to `DtoFrom`.
+fn extract_dto_field_attrs(
+    attrs: &[Attribute],
+    kind: ImplKind,
+    fallible: bool,
+) -> syn::Result<FieldAttrs> {
+    let mut cfg = FieldAttrs::default();
+    let mut seen_rename = false;
+    let mut seen_source_path = false;
+    let mut seen_index = false;
+    let mut seen_from_map = false;
+    let mut seen_key = false;
+    let mut seen_getter = false;
+    let mut seen_call = false;
+    let mut seen_clone = false;
+    let mut seen_ref = false;
+    let mut seen_unbox = false;
+    let mut seen_assert_type = false;
+    let mut seen_box = false;
+    let mut seen_transform = false;
+    let mut seen_args = false;
+    let mut seen_wrap_some = false;
+    let mut seen_flatten_some = false;
+    let mut seen_transform_expr = false;
+    let mut seen_transform_mut = false;
+    let mut seen_with = false;
+    let mut seen_try_transform = false;
+    let mut seen_reverse_transform = false;
+    let mut seen_from_source = false;
+    let mut seen_skip = false;
+    let mut seen_default = false;
+    let mut seen_into = false;
+    let mut seen_into_via = false;
+    let mut seen_auto_into = false;
+    let mut seen_collect = false;
+    let mut seen_map_values = false;
+    let mut seen_map_keys_values = false;
+    let mut seen_unwrap_or = false;
+    let mut seen_unwrap_or_default = false;
+    let mut seen_unwrap_result = false;
+    let mut seen_cow = false;
+    let mut seen_cast = false;
+    let mut seen_from_str = false;
+    let mut seen_from_str_or_default = false;
+    let mut seen_try_into = false;
+    let mut seen_error = false;
+    // Each conversion key as written, so a second one can be pointed at.
+    let mut conversion_spans: Vec<(&'static str, Span)> = Vec::new();
+
+    cfg.cfg_attrs = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .cloned()
+        .collect();
+    for attr in attrs {
+        if !attr.path().is_ident("dto") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if let Some(key) = kind
+                .field_keys(fallible)
+                .find(|key| key.conversion && meta.path.is_ident(key.name))
+            {
+                conversion_spans.push((key.name, meta.path.span()));
+            }
+            if meta.path.is_ident("direct") {
+                if cfg.direct.is_some() {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `direct`"));
+                }
+                cfg.direct = Some(meta.path.span());
+            } else if meta.path.is_ident("rename") {
+                let lit = meta.value()?.parse::<syn::LitStr>()?;
+                if lit.value().trim().is_empty() {
+                    return Err(syn::Error::new(lit.span(), "`rename` cannot be empty"));
+                }
+                if seen_rename {
+                    // Point at both, since either may be the one to drop.
+                    let mut error = syn::Error::new(
+                        lit.span(),
+                        "duplicate `rename`: a field reads exactly one source field; to pick between several, use `transform_fn = path, from_source`",
+                    );
+                    if let Some(ref first) = cfg.rename {
+                        error.combine(syn::Error::new(first.span(), "first `rename` here"));
+                    }
+                    return Err(error);
+                }
+                if seen_getter {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "`rename` conflicts with `getter`; use `rename = \"...\", call` or `getter` alone",
+                    ));
+                }
+                seen_rename = true;
+                cfg.rename_span = Some(lit.span());
+                cfg.rename = Some(field_ident(&lit.value(), lit.span()).ok_or_else(|| {
+                    syn::Error::new(
+                        lit.span(),
+                        format!("`rename` must be a valid field identifier, got `{}`", lit.value()),
+                    )
+                })?);
+            } else if meta.path.is_ident("source_path") {
+                let lit = meta.value()?.parse::<syn::LitStr>()?;
+                if seen_source_path {
+                    return Err(syn::Error::new(lit.span(), "duplicate `source_path`"));
+                }
+                seen_source_path = true;
+                cfg.source_path_span = Some(lit.span());
+                cfg.source_path = Some(parse_source_path(&lit)?);
+            } else if meta.path.is_ident("index") {
+                let lit = meta.value()?.parse::<syn::LitInt>()?;
+                if seen_index {
+                    return Err(syn::Error::new(lit.span(), "duplicate `index`"));
+                }
+                seen_index = true;
+                cfg.index = Some(syn::Index {
+                    index: lit.base10_parse()?,
+                    span: lit.span(),
+                });
+            } else if meta.path.is_ident("from_map") {
+                let lit = meta.value()?.parse::<syn::LitStr>()?;
+                if seen_from_map {
+                    return Err(syn::Error::new(lit.span(), "duplicate `from_map`"));
+                }
+                seen_from_map = true;
+                let map = field_ident(&lit.value(), lit.span()).ok_or_else(|| {
+                    syn::Error::new(
+                        lit.span(),
+                        "`from_map` must name the map field, e.g. `from_map = \"attributes\"`",
+                    )
+                })?;
+                cfg.rename_span = Some(lit.span());
+                cfg.from_map = Some(map);
+            } else if meta.path.is_ident("key") {
+                let lit = meta.value()?.parse::<syn::LitStr>()?;
+                if seen_key {
+                    return Err(syn::Error::new(lit.span(), "duplicate `key`"));
+                }
+                seen_key = true;
+                cfg.map_key = Some(lit);
+            } else if meta.path.is_ident("getter") {
+                let lit = meta.value()?.parse::<syn::LitStr>()?;
+                if seen_getter {
+                    return Err(syn::Error::new(lit.span(), "duplicate `getter`"));
+                }
+                if seen_rename {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "`getter` conflicts with `rename`; use `rename = \"...\", call` or `getter` alone",
+                    ));
+                }
+                seen_getter = true;
+                let method = field_ident(&lit.value(), lit.span()).ok_or_else(|| {
+                    syn::Error::new(lit.span(), "`getter` must name a method, e.g. `getter = \"name\"`")
+                })?;
+                cfg.rename_span = Some(lit.span());
+                cfg.rename = Some(method);
+                cfg.call = true;
+            } else if meta.path.is_ident("call") {
+                if seen_call {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `call`"));
+                }
+                seen_call = true;
+                cfg.call = true;
+            } else if meta.path.is_ident("clone") {
+                if seen_clone {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `clone`"));
+                }
+                seen_clone = true;
+                cfg.clone = true;
+            } else if meta.path.is_ident("ref") {
+                if seen_ref {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `ref`"));
+                }
+                seen_ref = true;
+                cfg.ref_arg = true;
+            } else if meta.path.is_ident("opt_ref") {
+                if cfg.opt_ref.is_some() {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `opt_ref`"));
+                }
+                if fallible {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "`opt_ref` maps an infallible transform over the option; it is not supported under `#[dto(fallible)]`",
+                    ));
+                }
+                cfg.opt_ref = Some(meta.path.span());
+            } else if meta.path.is_ident("unbox") {
+                if seen_unbox {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `unbox`"));
+                }
+                seen_unbox = true;
+                cfg.unbox = true;
+            } else if meta.path.is_ident("box") {
+                if seen_box {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `box`"));
+                }
+                if cfg.boxed_dyn.is_some() {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "`boxed_dyn` already boxes the value; drop `box`",
+                    ));
+                }
+                seen_box = true;
+                cfg.box_flag = true;
+            } else if meta.path.is_ident("boxed_dyn") {
+                let path: Path = meta.value()?.parse()?;
+                if cfg.boxed_dyn.is_some() {
+                    return Err(syn::Error::new_spanned(path, "duplicate `boxed_dyn`"));
+                }
+                if seen_box {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        "`boxed_dyn` already boxes the value; drop `box`",
+                    ));
+                }
+                if kind == ImplKind::Into {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        "`boxed_dyn` is not supported by `DtoInto`: a `Box<dyn Trait>` cannot be turned back into the concrete source value",
+                    ));
+                }
+                cfg.box_flag = true;
+                cfg.boxed_dyn = Some(path);
+            } else if let Some(wrapper) = Wrapper::from_path(&meta.path) {
+                match cfg.wrapper {
+                    Some((seen, _)) if seen == wrapper => {
+                        return Err(syn::Error::new(
+                            meta.path.span(),
+                            format!("duplicate `{}`", wrapper.key()),
+                        ));
+                    }
+                    Some((seen, _)) => {
+                        return Err(syn::Error::new(
+                            meta.path.span(),
+                            format!(
+                                "`{}` and `{}` cannot both wrap a field; keep one of them",
+                                seen.key(),
+                                wrapper.key()
+                            ),
+                        ));
+                    }
+                    None => cfg.wrapper = Some((wrapper, meta.path.span())),
+                }
+            } else if meta.path.is_ident("assert_type") {
+                if seen_assert_type {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `assert_type`"));
+                }
+                seen_assert_type = true;
+                cfg.assert_type = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("transform_fn") {
+                if seen_transform {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `transform_fn`",
+                    ));
+                }
+                seen_transform = true;
+                let val = meta.value()?;
+                // A plain path keeps path syntax, so `vec_into<A, B>` is not read as comparisons.
+                let fork = val.fork();
+                let is_path = fork.parse::<Path>().is_ok()
+                    && (fork.is_empty() || fork.peek(syn::Token![,]));
+                cfg.transform_fn = Some(if is_path {
+                    let path: Path = val.parse()?;
+                    syn::parse_quote! { #path }
+                } else {
+                    val.parse()?
+                });
+            } else if meta.path.is_ident("args") {
+                if seen_args {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `args`"));
+                }
+                seen_args = true;
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let args = syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated(
+                    &content,
+                )?;
+                cfg.args = Some(args.into_iter().collect());
+                cfg.args_span = Some(meta.path.span());
+            } else if meta.path.is_ident("with_fields") {
+                if cfg.with_fields.is_some() {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `with_fields`"));
+                }
+                if kind == ImplKind::Into {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "`with_fields` is not supported by `DtoInto`: one DTO field cannot be written back to several source fields",
+                    ));
+                }
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let names =
+                    syn::punctuated::Punctuated::<syn::LitStr, syn::Token![,]>::parse_terminated(
+                        &content,
+                    )?;
+                if names.is_empty() {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "`with_fields(..)` needs at least one source field name",
+                    ));
+                }
+                let fields = names
+                    .iter()
+                    .map(|lit| {
+                        field_ident(&lit.value(), lit.span()).ok_or_else(|| {
+                            syn::Error::new(
+                                lit.span(),
+                                "`with_fields` must list source field names, e.g. `with_fields(\"first\", \"last\")`",
+                            )
+                        })
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+                cfg.with_fields = Some(fields);
+                cfg.with_fields_span = Some(meta.path.span());
+            } else if meta.path.is_ident("wrap_some") {
+                if seen_wrap_some {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `wrap_some`"));
+                }
+                seen_wrap_some = true;
+                cfg.wrap_some = Some(meta.path.span());
+            } else if meta.path.is_ident("flatten_some") {
+                if seen_flatten_some {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `flatten_some`"));
+                }
+                seen_flatten_some = true;
+                cfg.flatten_some = Some(meta.path.span());
+            } else if meta.path.is_ident("or_default") {
+                if cfg.or_default.is_some() {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `or_default`"));
+                }
+                if fallible {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "`or_default` recovers from a failed transform in an infallible mapping; under `#[dto(fallible)]` the error is already propagated with `?`",
+                    ));
+                }
+                cfg.or_default = Some(meta.path.span());
+            } else if meta.path.is_ident("element_into") {
+                if cfg.element_into.is_some() {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `element_into`"));
+                }
+                cfg.element_into = Some(meta.path.span());
+            } else if meta.path.is_ident("then_into") {
+                if cfg.then_into.is_some() {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `then_into`"));
+                }
+                cfg.then_into = Some(meta.path.span());
+            } else if meta.path.is_ident("transform_expr") {
+                if seen_transform_expr {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `transform_expr`",
+                    ));
+                }
+                seen_transform_expr = true;
+                let val = meta.value()?;
+                cfg.transform_expr = Some(val.parse().map_err(|e| {
+                    syn::Error::new(
+                        e.span(),
+                        "`transform_expr` must be a closure like `|x| x.trim().to_string()`; use `transform_fn` for a path",
+                    )
+                })?);
+            } else if meta.path.is_ident("transform_mut") {
+                if seen_transform_mut {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `transform_mut`",
+                    ));
+                }
+                seen_transform_mut = true;
+                cfg.transform_mut = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("with") {
+                if seen_with {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `with`"));
+                }
+                seen_with = true;
+                cfg.with = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("try_transform_fn") {
+                require_fallible(&meta, kind, fallible, "try_transform_fn")?;
+                if seen_try_transform {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `try_transform_fn`",
+                    ));
+                }
+                seen_try_transform = true;
+                cfg.try_transform_fn = Some(parse_fn_path(meta.value()?)?);
+            } else if meta.path.is_ident("map_err") {
+                if cfg.map_err.is_some() {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `map_err`"));
+                }
+                cfg.map_err = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("reverse_transform_fn") {
+                if seen_reverse_transform {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `reverse_transform_fn`",
+                    ));
+                }
+                seen_reverse_transform = true;
+                cfg.reverse_transform_fn = Some(parse_fn_path(meta.value()?)?);
+            } else if meta.path.is_ident("from_source") {
+                if seen_from_source {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `from_source`"));
+                }
+                seen_from_source = true;
+                cfg.from_source = true;
+            } else if meta.path.is_ident("skip")
+                || (meta.path.is_ident("default") && !meta.input.peek(syn::Token![=]))
+            {
+                // A bare `default` reads better for fields the source lacks; it is `skip`.
+                if seen_skip {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `skip` (a bare `default` is the same as `skip`)",
+                    ));
+                }
+                seen_skip = true;
+                cfg.skip = true;
+                cfg.skip_span = Some(meta.path.span());
+                cfg.skip_as_default = meta.path.is_ident("default");
+            } else if meta.path.is_ident("default") {
+                if seen_default {
+                    let message = if cfg.default_fn.is_some() {
+                        "`default = ...` conflicts with `with_default_fn`; both initialize the field"
+                    } else {
+                        "duplicate `default`"
+                    };
+                    return Err(syn::Error::new(meta.path.span(), message));
+                }
+                seen_default = true;
+                cfg.default_expr = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("with_default_fn") {
+                if seen_default {
+                    let message = if cfg.default_fn.is_some() {
+                        "duplicate `with_default_fn`"
+                    } else {
+                        "`with_default_fn` conflicts with `default = ...`; both initialize the field"
+                    };
+                    return Err(syn::Error::new(meta.path.span(), message));
+                }
+                seen_default = true;
+                cfg.default_fn = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("into") {
+                if seen_into {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `into`"));
+                }
+                seen_into = true;
+                cfg.into_flag = true;
+                cfg.into_span = Some(meta.path.span());
+                if meta.input.peek(syn::Token![=]) {
+                    cfg.into_ty = Some(meta.value()?.parse()?);
+                }
+            } else if meta.path.is_ident("into_via") {
+                if seen_into_via {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `into_via`"));
+                }
+                seen_into_via = true;
+                cfg.into_via = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("auto_into") {
+                if seen_auto_into {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `auto_into`"));
+                }
+                seen_auto_into = true;
+                cfg.auto_into = true;
+            } else if meta.path.is_ident("collect") {
+                if seen_collect {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `collect`"));
+                }
+                seen_collect = true;
+                cfg.collect = true;
+            } else if meta.path.is_ident("map_values") {
+                if seen_map_values {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `map_values`"));
+                }
+                seen_map_values = true;
+                cfg.map_values = true;
+            } else if meta.path.is_ident("map_keys_values") {
+                if seen_map_keys_values {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `map_keys_values`",
+                    ));
+                }
+                seen_map_keys_values = true;
+                cfg.map_keys_values = true;
+            } else if meta.path.is_ident("unwrap_or") {
+                if seen_unwrap_or {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `unwrap_or`"));
+                }
+                seen_unwrap_or = true;
+                cfg.unwrap_or = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("unwrap_or_default") {
+                if seen_unwrap_or_default {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `unwrap_or_default`",
+                    ));
+                }
+                seen_unwrap_or_default = true;
+                cfg.unwrap_or_default = true;
+            } else if meta.path.is_ident("unwrap_result") {
+                if seen_unwrap_result {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `unwrap_result`"));
+                }
+                seen_unwrap_result = true;
+                cfg.unwrap_result = true;
+            } else if meta.path.is_ident("cow") {
+                if seen_cow {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `cow`"));
+                }
+                seen_cow = true;
+                cfg.cow = true;
+            } else if meta.path.is_ident("as") {
+                if seen_cast {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `as`"));
+                }
+                seen_cast = true;
+                cfg.cast = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("naive_to_utc") || meta.path.is_ident("utc_to_naive") {
+                let to_utc = meta.path.is_ident("naive_to_utc");
+                let key = if to_utc { "naive_to_utc" } else { "utc_to_naive" };
+                if !cfg!(feature = "chrono") {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        format!("`{key}` requires the `chrono` feature of `simple_dto_mapper_derive`"),
+                    ));
+                }
+                let seen = if to_utc {
+                    &mut cfg.naive_to_utc
+                } else {
+                    &mut cfg.utc_to_naive
+                };
+                if *seen {
+                    return Err(syn::Error::new(meta.path.span(), format!("duplicate `{key}`")));
+                }
+                *seen = true;
+            } else if meta.path.is_ident("from_str") {
+                if seen_from_str {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `from_str`"));
+                }
+                seen_from_str = true;
+                cfg.from_str = true;
+            } else if meta.path.is_ident("from_str_or_default") {
+                if seen_from_str_or_default {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `from_str_or_default`",
+                    ));
+                }
+                seen_from_str_or_default = true;
+                cfg.from_str_or_default = true;
+            } else if meta.path.is_ident("try_into_expect") {
+                let lit = meta.value()?.parse::<syn::LitStr>()?;
+                if cfg.try_into_expect.is_some() {
+                    return Err(syn::Error::new(lit.span(), "duplicate `try_into_expect`"));
+                }
+                cfg.try_into_expect = Some(lit);
+            } else if meta.path.is_ident("try_into") {
+                require_fallible(&meta, kind, fallible, "try_into")?;
+                if seen_try_into {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `try_into`"));
+                }
+                seen_try_into = true;
+                cfg.try_into_flag = true;
+            } else if meta.path.is_ident("error") {
+                require_fallible(&meta, kind, fallible, "error")?;
+                if seen_error {
+                    return Err(syn::Error::new(meta.path.span(), "duplicate `error`"));
+                }
+                seen_error = true;
+                cfg.error_ty = Some(meta.value()?.parse()?);
+            } else {
+                let keys: Vec<&str> = kind.field_keys(fallible).map(|key| key.name).collect();
+                return Err(syn::Error::new(
+                    meta.path.span(),
+                    format!("unknown #[dto(...)] key; expected {}", list_keys(&keys)),
+                ));
+            }
+            Ok(())
+        })?;
+    }
+
+    let conversions = [
+        cfg.transform_fn.is_some(),
+        cfg.transform_expr.is_some(),
+        cfg.transform_mut.is_some(),
+        cfg.with.is_some(),
+        cfg.try_transform_fn.is_some(),
+        cfg.into_flag,
+        cfg.into_via.is_some(),
+        cfg.auto_into,
+        cfg.collect,
+        cfg.map_values,
+        cfg.map_keys_values,
+        cfg.unwrap_or.is_some(),
+        cfg.unwrap_or_default,
+        // a fallback already unwraps the `Result`, so the pair counts once
+        cfg.unwrap_result && cfg.unwrap_or.is_none() && !cfg.unwrap_or_default,
+        cfg.cow,
+        cfg.cast.is_some(),
+        cfg.naive_to_utc,
+        cfg.utc_to_naive,
+        cfg.from_str,
+        cfg.from_str_or_default,
+        cfg.try_into_flag,
+        cfg.try_into_expect.is_some(),
+    ]
+    .iter()
+    .filter(|set| **set)
+    .count();
+
+    if let (Some((wrapper, span)), true) = (cfg.wrapper, cfg.box_flag) {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "`{}` wraps the value itself; it cannot be combined with `box` or `boxed_dyn`",
+                wrapper.key()
+            ),
+        ));
+    }
+    if let Some(span) = cfg.direct {
+        let conflict = if cfg.skip || cfg.default_expr.is_some() || cfg.default_fn.is_some() {
+            Some("`skip` / `default`")
+        } else if cfg.rename.is_some()
+            || cfg.source_path.is_some()
+            || cfg.index.is_some()
+            || cfg.from_map.is_some()
+            || cfg.call
+        {
+            Some("`rename` and the other keys naming a source field")
+        } else if conversions > 0
+            || cfg.from_source
+            || cfg.unbox
+            || cfg.box_flag
+            || cfg.wrapper.is_some()
+        {
+            Some("`transform_fn`, `into`, and the other conversions")
+        } else {
+            None
+        };
+        if let Some(conflict) = conflict {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "`direct` maps the same-named source field as-is; it conflicts with {conflict}"
+                ),
+            ));
+        }
+    }
+    let names_source = cfg.rename.is_some()
+        || cfg.source_path.is_some()
+        || cfg.index.is_some()
+        || cfg.from_map.is_some();
+    if (cfg.skip || cfg.default_expr.is_some() || cfg.default_fn.is_some()) && names_source {
+        let key = match (cfg.skip, cfg.skip_as_default, cfg.default_fn.is_some()) {
+            (true, false, _) => "skip",
+            (true, true, _) => "default",
+            (false, _, true) => "with_default_fn",
+            (false, _, false) => "default = ...",
+        };
+        return Err(syn::Error::new(
+            cfg.rename_span
+                .or(cfg.source_path_span)
+                .or(cfg.index.as_ref().map(|index| index.span))
+                .unwrap_or_else(Span::call_site),
+            format!(
+                "`{key}` cannot be combined with `rename` or another key naming a source field: the field's existence is checked at compile time, so there is no read-or-default fallback; keep one of them"
+            ),
+        ));
+    }
+    if (cfg.skip || cfg.default_expr.is_some() || cfg.default_fn.is_some())
+        && (cfg.rename.is_some()
+            || cfg.source_path.is_some()
+            || cfg.index.is_some()
+            || cfg.from_map.is_some()
+            || cfg.call
+            || cfg.clone
+            || cfg.ref_arg
+            || cfg.opt_ref.is_some()
+            || cfg.unbox
+            || cfg.box_flag
+            || cfg.wrapper.is_some()
+            || cfg.assert_type.is_some()
+            || cfg.reverse_transform_fn.is_some()
+            || cfg.from_source
+            || conversions > 0)
+    {
+        let key = if cfg.skip {
+            "#[dto(skip)]"
+        } else if cfg.default_fn.is_some() {
+            "#[dto(with_default_fn = ...)]"
+        } else {
+            "#[dto(default = ...)]"
+        };
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!(
+                "`{key}` cannot be combined with mapping keys such as `rename`, `transform_fn`, or `into`"
+            ),
+        ));
+    }
+    if let (Some(f), Some(into_span)) = (&cfg.transform_fn, cfg.into_span) {
+        // both keys are underlined; a `rename` alongside them is not part of the conflict
+        let renamed = if cfg.rename.is_some() {
+            " (`rename` works with either)"
+        } else {
+            ""
+        };
+        let mut error = syn::Error::new_spanned(
+            f,
+            format!("`transform_fn` and `into` cannot both be set: the transform already produces the field's value, so convert inside it or drop one of them{renamed}"),
+        );
+        error.combine(syn::Error::new(
+            into_span,
+            "`into` conflicts with the `transform_fn` on this field",
+        ));
+        return Err(error);
+    }
+    if conversions > 1 {
+        let keys: Vec<&str> = kind
+            .field_keys(fallible)
+            .filter(|key| key.conversion)
+            .map(|key| key.name)
+            .collect();
+        // `unwrap_result` beside a fallback counts once, so it is not the second conversion
+        let paired = cfg.unwrap_or.is_some() || cfg.unwrap_or_default;
+        let span = conversion_spans
+            .iter()
+            .filter(|(key, _)| !(paired && *key == "unwrap_result"))
+            .nth(1)
+            .map_or_else(Span::call_site, |(_, span)| *span);
+        return Err(syn::Error::new(
+            span,
+            format!("only one of {} may be set on a field", list_keys(&keys)),
+        ));
+    }
+    if cfg.from_source
+        && cfg.transform_fn.is_none()
+        && cfg.transform_expr.is_none()
+        && cfg.try_transform_fn.is_none()
+    {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`from_source` requires `transform_fn`, `transform_expr`, or `try_transform_fn`",
+        ));
+    }
+    if cfg.from_source
+        && (cfg.rename.is_some()
+            || cfg.source_path.is_some()
+            || cfg.index.is_some()
+            || cfg.from_map.is_some()
+            || cfg.call
+            || cfg.ref_arg
+            || cfg.opt_ref.is_some()
+            || cfg.unbox
+            || cfg.assert_type.is_some())
+    {
+        return Err(syn::Error::new(
+            cfg.rename_span
+                .or(cfg.source_path_span)
+                .or(cfg.index.as_ref().map(|index| index.span))
+                .unwrap_or_else(Span::call_site),
+            "`rename`/`source_path`/`index`/`from_map`/`getter`/`ref`/`unbox`/`assert_type` have no effect with `from_source`; the transform receives the whole source",
+        ));
+    }
+    if let (Some(_), Some(span)) = (&cfg.rename, cfg.source_path_span) {
+        return Err(syn::Error::new(
+            span,
+            "`source_path` conflicts with `rename`; both name the source field",
+        ));
+    }
+    if let Some(ref index) = cfg.index {
+        if cfg.rename.is_some() || cfg.source_path.is_some() || cfg.call {
+            return Err(syn::Error::new(
+                index.span,
+                "`index` conflicts with `rename`, `source_path`, and `getter`/`call`; they all name the source field",
+            ));
+        }
+    }
+    if let Some(ref map) = cfg.from_map {
+        if cfg.rename.is_some() || cfg.source_path.is_some() || cfg.index.is_some() || cfg.call {
+            return Err(syn::Error::new(
+                map.span(),
+                "`from_map` conflicts with `rename`, `source_path`, `index`, and `getter`/`call`; use `key` to pick the entry",
+            ));
+        }
+    } else if let Some(ref key) = cfg.map_key {
+        return Err(syn::Error::new(
+            key.span(),
+            "`key` requires `from_map = \"field\"` naming the map to read",
+        ));
+    }
+    if cfg.ref_arg
+        && cfg.transform_fn.is_none()
+        && cfg.transform_expr.is_none()
+        && cfg.with.is_none()
+        && cfg.try_transform_fn.is_none()
+    {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`ref` requires `transform_fn`, `transform_expr`, `with`, or `try_transform_fn`",
+        ));
+    }
+    if let Some(span) = cfg.opt_ref {
+        if cfg.transform_fn.is_none() {
+            return Err(syn::Error::new(
+                span,
+                "`opt_ref` requires `transform_fn`; it maps that function over `source.field.as_ref()`",
+            ));
+        }
+        if cfg.ref_arg || cfg.with_fields.is_some() {
+            return Err(syn::Error::new(
+                span,
+                "`opt_ref` already passes the transform a reference; it conflicts with `ref` and `with_fields`",
+            ));
+        }
+    }
+    if let Some(span) = cfg.wrap_some.or(cfg.flatten_some) {
+        if cfg.wrap_some.is_some() && cfg.flatten_some.is_some() {
+            return Err(syn::Error::new(
+                span,
+                "`wrap_some` and `flatten_some` adapt the transform result in opposite directions; use one",
+            ));
+        }
+        if cfg.transform_fn.is_none()
+            && cfg.transform_expr.is_none()
+            && cfg.with.is_none()
+            && cfg.try_transform_fn.is_none()
+        {
+            return Err(syn::Error::new(
+                span,
+                "`wrap_some` / `flatten_some` adapt a transform's result and require `transform_fn`, `transform_expr`, `with`, or `try_transform_fn`",
+            ));
+        }
+    }
+    if let Some(span) = cfg.or_default {
+        if cfg.transform_fn.is_none() && cfg.transform_expr.is_none() && cfg.with.is_none() {
+            return Err(syn::Error::new(
+                span,
+                "`or_default` unwraps a transform's `Result` or `Option` and requires `transform_fn`, `transform_expr`, or `with`; use `unwrap_or_default` for an `Option` source field",
+            ));
+        }
+    }
+    if let Some(span) = cfg.element_into {
+        if cfg.then_into.is_some() {
+            return Err(syn::Error::new(
+                span,
+                "`element_into` and `then_into` both convert the transform's result; use `then_into` for the whole value or `element_into` for each element",
+            ));
+        }
+        if cfg.transform_fn.is_none()
+            && cfg.transform_expr.is_none()
+            && cfg.with.is_none()
+            && cfg.try_transform_fn.is_none()
+        {
+            return Err(syn::Error::new(
+                span,
+                "`element_into` converts the elements of a transform's collection and requires `transform_fn`, `transform_expr`, `with`, or `try_transform_fn`; use `collect` for the field itself",
+            ));
+        }
+    }
+    if let Some(span) = cfg.then_into {
+        if cfg.transform_fn.is_none()
+            && cfg.transform_expr.is_none()
+            && cfg.with.is_none()
+            && cfg.try_transform_fn.is_none()
+        {
+            return Err(syn::Error::new(
+                span,
+                "`then_into` converts a transform's result and requires `transform_fn`, `transform_expr`, `with`, or `try_transform_fn`; use `into` to convert the field itself",
+            ));
+        }
+    }
+    if let Some(span) = cfg.args_span {
+        if cfg.transform_fn.is_none() && cfg.try_transform_fn.is_none() {
+            return Err(syn::Error::new(
+                span,
+                "`args(..)` requires `transform_fn` or `try_transform_fn`; it appends arguments to that call",
+            ));
+        }
+    }
+    if let Some(span) = cfg.with_fields_span {
+        if cfg.transform_fn.is_none() && cfg.try_transform_fn.is_none() {
+            return Err(syn::Error::new(
+                span,
+                "`with_fields(..)` requires `transform_fn` or `try_transform_fn`; it lists that call's arguments",
+            ));
+        }
+        if cfg.rename.is_some()
+            || cfg.source_path.is_some()
+            || cfg.index.is_some()
+            || cfg.from_map.is_some()
+            || cfg.call
+            || cfg.unbox
+            || cfg.assert_type.is_some()
+            || cfg.from_source
+        {
+            return Err(syn::Error::new(
+                span,
+                "`with_fields` names the source fields the transform receives; it conflicts with `rename`, `source_path`, `from_source`, and the other keys that choose a source value",
+            ));
+        }
+    }
+    if cfg.with.is_some() && cfg.reverse_transform_fn.is_some() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`with` already supplies the reverse conversion (`to_source`); drop `reverse_transform_fn`",
+        ));
+    }
+    if let Some(ref map_err) = cfg.map_err {
+        let returns_result = cfg.try_transform_fn.is_some()
+            || (fallible
+                && (cfg.transform_fn.is_some()
+                    || cfg.transform_expr.is_some()
+                    || cfg.with.is_some()));
+        if !returns_result {
+            return Err(syn::Error::new_spanned(
+                map_err,
+                "`map_err` needs a `Result`-returning transform: `try_transform_fn`, or `transform_fn` / `transform_expr` / `with` under `fallible`",
+            ));
+        }
+    }
+
+    Ok(cfg)
+}
+
+/// A field or method name from a string attribute, raw (`r#type`) when it is a keyword.
+///
+/// Accepts the name with or without the `r#` prefix; `None` if it is not an identifier at all.
+fn field_ident(name: &str, span: Span) -> Option<Ident> {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+    if syn::parse_str::<Ident>(name).is_ok() {
+        Some(Ident::new(name, span))
+    } else if syn::parse_str::<Ident>(&format!("r#{name}")).is_ok() {
+        Some(Ident::new_raw(name, span))
+    } else {
+        None
+    }
+}
+
+/// Parses a dotted `source_path` such as `"address.city"` into field accesses.
+fn parse_source_path(lit: &syn::LitStr) -> syn::Result<Vec<syn::Member>> {
+    let value = lit.value();
+    value
+        .split('.')
+        .map(|segment| {
+            if segment.is_empty() {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!(
+                        "`source_path` has an empty segment in `{value}`; separate field names and tuple indices with single dots, like \"pair.0.name\""
+                    ),
+                ));
+            }
+            // `0`, `1`, ... read tuple fields; a leading zero is no valid tuple index
+            if segment.bytes().all(|b| b.is_ascii_digit()) {
+                return match segment.parse::<u32>() {
+                    Ok(index) if segment == index.to_string() => {
+                        Ok(syn::Member::Unnamed(syn::Index {
+                            index,
+                            span: lit.span(),
+                        }))
+                    }
+                    _ => Err(syn::Error::new(
+                        lit.span(),
+                        format!("`source_path` segment `{segment}` is not a valid tuple index"),
+                    )),
+                };
+            }
+            match field_ident(segment, lit.span()) {
+                Some(ident) => Ok(syn::Member::Named(ident)),
+                None => Err(syn::Error::new(
+                    lit.span(),
+                    format!(
+                        "`source_path` must be a dotted path of field names and tuple indices like \"address.city\" or \"pair.0\", got `{value}`"
+                    ),
+                )),
+            }
+        })
+        .collect()
+}
+
+/// Case conventions accepted by `rename_all`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Snake,
+    ScreamingSnake,
+    Camel,
+    Pascal,
+}
+
+impl RenameRule {
+    fn parse(lit: &syn::LitStr) -> syn::Result<Self> {
+        Ok(match lit.value().as_str() {
+            "lowercase" => RenameRule::Lower,
+            "UPPERCASE" => RenameRule::Upper,
+            "snake_case" => RenameRule::Snake,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+            "camelCase" => RenameRule::Camel,
+            "PascalCase" => RenameRule::Pascal,
+            "kebab-case" | "SCREAMING-KEBAB-CASE" => {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "`rename_all` names source fields, and kebab-case names are not valid Rust fields",
+                ));
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!(
+                        "unknown `rename_all` case; expected {}",
+                        list_keys(&[
+                            "lowercase",
+                            "UPPERCASE",
+                            "snake_case",
+                            "SCREAMING_SNAKE_CASE",
+                            "camelCase",
+                            "PascalCase",
+                        ])
+                    ),
+                ));
+            }
+        })
+    }
+
+    /// Converts a DTO field name, written in any of the supported cases, to this case.
+    fn apply(self, name: &str) -> String {
+        let words = split_words(name);
+        let capitalize = |w: &String| {
+            let mut chars = w.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        };
+        match self {
+            RenameRule::Lower => words.concat(),
+            RenameRule::Upper => words.concat().to_uppercase(),
+            RenameRule::Snake => words.join("_"),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+            RenameRule::Camel => {
+                let mut out = words.first().cloned().unwrap_or_default();
+                out.extend(words.iter().skip(1).map(capitalize));
+                out
+            }
+            RenameRule::Pascal => words.iter().map(capitalize).collect(),
+        }
+    }
+}
+
+/// Splits an identifier into lowercase words at underscores and lower-to-upper case changes,
+/// so `user_id`, `userId`, and `UserId` all become `["user", "id"]`.
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in name.split('_').filter(|p| !p.is_empty()) {
+        let mut word = String::new();
+        let mut prev_lower = false;
+        for c in part.chars() {
+            if c.is_uppercase() && prev_lower {
+                words.push(std::mem::take(&mut word));
+            }
+            prev_lower = c.is_lowercase() || c.is_ascii_digit();
+            word.extend(c.to_lowercase());
+        }
+        words.push(word);
+    }
+    words
+}
+
+/// `NaiveDateTime` → `DateTime<Utc>` (or back, for `to_utc == false`), mapped over an `Option`
+/// field. The naive value is taken as UTC, as `DateTime::from_naive_utc_and_offset` does.
+fn chrono_convert(
+    to_utc: bool,
+    ty: &syn::Type,
+    value: proc_macro2::TokenStream,
+    span: Span,
+) -> proc_macro2::TokenStream {
+    let convert = |value: proc_macro2::TokenStream| {
+        if to_utc {
+            quote_spanned! { span =>
+                ::chrono::DateTime::<::chrono::Utc>::from_naive_utc_and_offset(#value, ::chrono::Utc)
+            }
+        } else {
+            quote_spanned! { span => ::chrono::DateTime::<::chrono::Utc>::naive_utc(&#value) }
+        }
+    };
+    if container_of(ty) == Container::Option {
+        let each = convert(quote_spanned! { span => __dto_dt });
+        quote_spanned! { span => ::core::option::Option::map(#value, |__dto_dt| #each) }
+    } else {
+        convert(value)
+    }
+}
+
+/// Container shapes `auto_into`, `map_values`, `cow`, and `skip` recognise on the target field type.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Container {
+    Vec,
+    Option,
+    /// `HashMap<K, V>` or `BTreeMap<K, V>`.
+    Map,
+    /// `Cow<'a, B>`.
+    Cow,
+    Other,
+}
+
+/// Classifies a field type by the last segment of its path (`Vec<U>`, `std::vec::Vec<U>`,
+/// `Option<U>`, `HashMap<K, V>`, ...). Only the name is inspected, so type aliases are not seen
+/// through.
+fn container_of(ty: &syn::Type) -> Container {
+    let Some(segment) = last_type_segment(ty) else {
+        return Container::Other;
+    };
+    let arg_count = match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => args.args.len(),
+        _ => 0,
+    };
+    match arg_count {
+        1 if segment.ident == "Vec" => Container::Vec,
+        1 if segment.ident == "Option" => Container::Option,
+        // `HashMap<K, V, S>` with a custom hasher still collects from `(K, V)` pairs.
+        2 | 3 if segment.ident == "HashMap" => Container::Map,
+        2 if segment.ident == "BTreeMap" => Container::Map,
+        2 if segment.ident == "Cow" => Container::Cow,
+        _ => Container::Other,
+    }
+}
+
+/// `T` of an `Arc<T>` / `Rc<T>` source type, matched by the last path segment like `container_of`.
+fn shared_pointee(ty: &syn::Type) -> Option<&syn::Type> {
+    let segment = last_type_segment(ty)?;
+    if segment.ident != "Arc" && segment.ident != "Rc" {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            syn::GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn last_type_segment(ty: &syn::Type) -> Option<&syn::PathSegment> {
+    match ty {
+        syn::Type::Path(tp) if tp.qself.is_none() => tp.path.segments.last(),
+        syn::Type::Group(g) => last_type_segment(&g.elem),
+        syn::Type::Paren(p) => last_type_segment(&p.elem),
+        _ => None,
+    }
+}
+
+/// Rejects fallible-only field keys when expanding `DtoFrom` without `fallible`.
+fn require_fallible(
+    meta: &syn::meta::ParseNestedMeta,
+    kind: ImplKind,
+    fallible: bool,
+    key: &str,
+) -> syn::Result<()> {
+    if kind != ImplKind::From || fallible {
+        return Ok(());
+    }
+    Err(syn::Error::new(
+        meta.path.span(),
+        format!("`{key}` is only supported by `#[derive(DtoTryFrom)]` or `#[dto(fallible)]`"),
+    ))
+}
+
+/// Rewrites `Page::<T>` to `Page<T>` throughout a `from` type, so both spellings compare equal.
+fn strip_turbofish(ty: &mut syn::Type) {
+    match ty {
+        syn::Type::Reference(r) => strip_turbofish(&mut r.elem),
+        syn::Type::Paren(p) => strip_turbofish(&mut p.elem),
+        syn::Type::Slice(s) => strip_turbofish(&mut s.elem),
+        syn::Type::Array(a) => strip_turbofish(&mut a.elem),
+        syn::Type::Tuple(t) => t.elems.iter_mut().for_each(strip_turbofish),
+        syn::Type::Path(p) => {
+            for segment in &mut p.path.segments {
+                if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                    args.colon2_token = None;
+                    for arg in &mut args.args {
+                        if let syn::GenericArgument::Type(inner) = arg {
+                            strip_turbofish(inner);
+                        }
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Whether a `from` type is `Self` (or `&Self`), which would map the struct into itself.
+fn names_self(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Reference(r) => names_self(&r.elem),
+        syn::Type::Paren(p) => names_self(&p.elem),
+        syn::Type::Path(p) => p.qself.is_none() && p.path.is_ident("Self"),
+        _ => false,
+    }
+}
+
+fn extract_dto_struct_attrs(attrs: &[Attribute], kind: ImplKind) -> syn::Result<StructAttrs> {
+    let mut sources: Vec<syn::Type> = Vec::new();
+    let mut error: Option<Path> = None;
+    let mut seen_error = false;
+    let mut generate_error = false;
+    let mut generate_span: Option<Span> = None;
+    let mut by_ref = false;
+    let mut both_refs = false;
+    let mut fallible = false;
+    let mut reverse_default = false;
+    let mut fill_default: Option<Span> = None;
+    let mut only_annotated = false;
+    let mut no_inline = false;
+    let mut collection_helper = false;
+    let mut inherent = false;
+    let mut const_fn: Option<Span> = None;
+    let mut post_build: Option<Path> = None;
+    let mut debug_assert_invariant: Option<(proc_macro2::TokenStream, syn::LitStr)> = None;
+    let mut exhaustive: Option<Span> = None;
+    let mut auto_nested = false;
+    let mut generics_from_source: Option<Span> = None;
+    let mut debug = false;
+    let mut cfg_feature: Option<syn::LitStr> = None;
+    let mut rename_all: Option<RenameRule> = None;
+    let mut strip_prefix: Option<String> = None;
+    let mut bounds: Vec<syn::WherePredicate> = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident("dto") {
             continue;
         }
         attr.parse_nested_meta(|meta| {
             if meta.path.is_ident("from") {
-                if seen_from {
+                let key_span = meta.path.span();
+                let value = meta.value()?;
+                if value.is_empty() || value.peek(syn::Token![,]) {
+                    return Err(syn::Error::new(key_span, "`from` requires a type path"));
+                }
+                let mut ty: syn::Type = value.parse()?;
+                strip_turbofish(&mut ty);
+                if names_self(&ty) {
+                    return Err(syn::Error::new_spanned(
+                        &ty,
+                        "`from` cannot be `Self`; name the source type to map from",
+                    ));
+                }
+                // Distinct sources accumulate; the same type listed twice is a mistake.
+                let rendered = quote!(#ty).to_string();
+                if sources.iter().any(|seen| quote!(#seen).to_string() == rendered) {
+                    return Err(syn::Error::new_spanned(
+                        &ty,
+                        format!(
+                            "duplicate `from` on struct: `{}` is already a source",
+                            type_display(&ty)
+                        ),
+                    ));
+                }
+                sources.push(ty);
+            } else if meta.path.is_ident("error") {
+                if seen_error {
                     return Err(syn::Error::new(
                         meta.path.span(),
-                        "duplicate `from` on struct",
+                        "duplicate `error` on struct",
                     ));
                 }
-                let path: Path = meta.value()?.parse()?;
-                result = Some(path);
-                seen_from = true;
+                if meta.input.peek(syn::token::Paren) {
+                    meta.parse_nested_meta(|inner| {
+                        if inner.path.is_ident("generate") {
+                            generate_error = true;
+                            Ok(())
+                        } else {
+                            Err(inner.error("expected `error(generate)` or `error = Type`"))
+                        }
+                    })?;
+                    generate_span = Some(meta.path.span());
+                } else {
+                    error = Some(meta.value()?.parse()?);
+                }
+                seen_error = true;
+            } else if meta.path.is_ident("by_ref") {
+                if by_ref {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `by_ref` on struct",
+                    ));
+                }
+                by_ref = true;
+            } else if meta.path.is_ident("fallible") {
+                if fallible {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `fallible` on struct",
+                    ));
+                }
+                fallible = true;
+            } else if meta.path.is_ident("both_refs") {
+                if both_refs {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `both_refs` on struct",
+                    ));
+                }
+                both_refs = true;
+            } else if meta.path.is_ident("bound") || meta.path.is_ident("where") {
+                // `where = "..."` is the same key, for readers who think of it as the impl's clause
+                let key = if meta.path.is_ident("where") {
+                    "where"
+                } else {
+                    "bound"
+                };
+                let lit = meta.value()?.parse::<syn::LitStr>()?;
+                let parsed = lit
+                    .parse_with(|input: syn::parse::ParseStream| {
+                        input.parse::<Option<syn::Token![where]>>()?;
+                        syn::punctuated::Punctuated::<syn::WherePredicate, syn::Token![,]>::parse_terminated(input)
+                    })
+                    .map_err(|e| {
+                        syn::Error::new(
+                            lit.span(),
+                            format!("`{key}` must be a list of where-predicates like \"T: Clone\": {e}"),
+                        )
+                    })?;
+                bounds.extend(parsed);
+            } else if meta.path.is_ident("rename_all") {
+                let lit = meta.value()?.parse::<syn::LitStr>()?;
+                if rename_all.is_some() {
+                    return Err(syn::Error::new(lit.span(), "duplicate `rename_all` on struct"));
+                }
+                rename_all = Some(RenameRule::parse(&lit)?);
+            } else if meta.path.is_ident("strip_prefix") {
+                let lit = meta.value()?.parse::<syn::LitStr>()?;
+                if strip_prefix.is_some() {
+                    return Err(syn::Error::new(lit.span(), "duplicate `strip_prefix` on struct"));
+                }
+                let prefix = lit.value();
+                if prefix.is_empty() || field_ident(&format!("{prefix}x"), lit.span()).is_none() {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "`strip_prefix` must be the start of a field name, e.g. `strip_prefix = \"user_\"`",
+                    ));
+                }
+                strip_prefix = Some(prefix);
+            } else if meta.path.is_ident("reverse_default") {
+                if reverse_default {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `reverse_default` on struct",
+                    ));
+                }
+                reverse_default = true;
+            } else if meta.path.is_ident("fill_default") {
+                if fill_default.is_some() {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `fill_default` on struct",
+                    ));
+                }
+                fill_default = Some(meta.path.span());
+            } else if meta.path.is_ident("only_annotated") {
+                if only_annotated {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `only_annotated` on struct",
+                    ));
+                }
+                only_annotated = true;
+            } else if meta.path.is_ident("no_inline") {
+                if no_inline {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `no_inline` on struct",
+                    ));
+                }
+                no_inline = true;
+            } else if meta.path.is_ident("collection_helper") {
+                if collection_helper {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `collection_helper` on struct",
+                    ));
+                }
+                collection_helper = true;
+            } else if meta.path.is_ident("inherent") {
+                if inherent {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `inherent` on struct",
+                    ));
+                }
+                inherent = true;
+            } else if meta.path.is_ident("const_fn") {
+                if const_fn.is_some() {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `const_fn` on struct",
+                    ));
+                }
+                const_fn = Some(meta.path.span());
+            } else if meta.path.is_ident("post_build") {
+                if post_build.is_some() {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `post_build` on struct",
+                    ));
+                }
+                post_build = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("debug_assert_invariant") {
+                if debug_assert_invariant.is_some() {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `debug_assert_invariant` on struct",
+                    ));
+                }
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                let check: syn::Expr = lit.parse().map_err(|_| {
+                    syn::Error::new(
+                        lit.span(),
+                        "`debug_assert_invariant` must be a boolean expression over `self`, like \"self.start <= self.end\"",
+                    )
+                })?;
+                debug_assert_invariant = Some((self_to_out(quote!(#check)), lit));
+            } else if meta.path.is_ident("exhaustive") {
+                if exhaustive.is_some() {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `exhaustive` on struct",
+                    ));
+                }
+                exhaustive = Some(meta.path.span());
+            } else if meta.path.is_ident("auto_nested") {
+                if auto_nested {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `auto_nested` on struct",
+                    ));
+                }
+                auto_nested = true;
+            } else if meta.path.is_ident("generics_from_source") {
+                if generics_from_source.is_some() {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `generics_from_source` on struct",
+                    ));
+                }
+                if kind == ImplKind::Into {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "`generics_from_source` is not supported by `DtoInto`: a parameter the DTO does not declare would be unconstrained in `impl From<Dto> for Source<T>`",
+                    ));
+                }
+                generics_from_source = Some(meta.path.span());
+            } else if meta.path.is_ident("debug") {
+                if debug {
+                    return Err(syn::Error::new(
+                        meta.path.span(),
+                        "duplicate `debug` on struct",
+                    ));
+                }
+                debug = true;
+            } else if meta.path.is_ident("cfg_feature") {
+                let lit = meta.value()?.parse::<syn::LitStr>()?;
+                if cfg_feature.is_some() {
+                    return Err(syn::Error::new(lit.span(), "duplicate `cfg_feature` on struct"));
+                }
+                if lit.value().trim().is_empty() {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "`cfg_feature` must name a cargo feature, e.g. `cfg_feature = \"serde\"`",
+                    ));
+                }
+                cfg_feature = Some(lit);
             } else {
                 return Err(syn::Error::new(
                     meta.path.span(),
-                    "unknown struct-level #[dto(...)] key; expected `from`",
+                    format!(
+                        "unknown struct-level #[dto(...)] key; expected {}",
+                        list_keys(kind.struct_keys())
+                    ),
                 ));
             }
             Ok(())
         })?;
     }
-    result.ok_or_else(|| {
-        syn::Error::new(
+    if sources.is_empty() {
+        return Err(syn::Error::new(
             Span::call_site(),
             "Expected `#[dto(from = Type)]` on the struct.",
-        )
+        ));
+    }
+    // A reference source (`from = &'a Source`) is the `by_ref` mode with a caller-chosen lifetime.
+    let references = sources
+        .iter()
+        .filter(|ty| matches!(ty, syn::Type::Reference(_)))
+        .count();
+    if references > 0 && references < sources.len() {
+        return Err(syn::Error::new_spanned(
+            &sources[0],
+            "`from` types must be either all references or all owned",
+        ));
+    }
+    if references > 0 && kind == ImplKind::Into {
+        return Err(syn::Error::new_spanned(
+            &sources[0],
+            "`DtoInto` cannot produce a reference; use an owned `from` type",
+        ));
+    }
+    by_ref |= references > 0;
+    // Fields cannot be moved out of an `Arc` / `Rc`, so such a source is read like `&Source`.
+    let shared = sources
+        .iter()
+        .filter(|ty| shared_pointee(ty).is_some())
+        .count();
+    if shared > 0 && shared < sources.len() {
+        return Err(syn::Error::new_spanned(
+            &sources[0],
+            "`from` types must be either all `Arc` / `Rc` or all plain",
+        ));
+    }
+    if shared > 0 && kind == ImplKind::Into {
+        return Err(syn::Error::new_spanned(
+            &sources[0],
+            "`DtoInto` cannot produce an `Arc` / `Rc`; use the inner type in `from`",
+        ));
+    }
+    if shared > 0 && both_refs {
+        return Err(syn::Error::new_spanned(
+            &sources[0],
+            "`both_refs` does not apply to an `Arc` / `Rc` source, which is always read by reference",
+        ));
+    }
+    by_ref |= shared > 0;
+    if both_refs && by_ref {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`both_refs` already generates the by-ref impl; drop `by_ref` (or the `&` in `from`)",
+        ));
+    }
+    if kind == ImplKind::From && !fallible {
+        let message = "`error` on `DtoFrom` requires `fallible` (or use `#[derive(DtoTryFrom)]`)";
+        if let Some(ref error) = error {
+            return Err(syn::Error::new_spanned(error, message));
+        }
+        if let Some(span) = generate_span {
+            return Err(syn::Error::new(span, message));
+        }
+    }
+    // `from_const` moves each field out of an owned source, with nothing to call in between.
+    if let Some(span) = const_fn {
+        let conflict = [
+            (kind == ImplKind::TryFrom || fallible, "a fallible impl"),
+            (by_ref || both_refs, "`by_ref` or a borrowed `from` type"),
+            (sources.len() > 1, "more than one `from` type"),
+            (post_build.is_some(), "`post_build`"),
+            (debug_assert_invariant.is_some(), "`debug_assert_invariant`"),
+            (fill_default.is_some(), "`fill_default`"),
+            (auto_nested, "`auto_nested`"),
+            (generics_from_source.is_some(), "`generics_from_source`"),
+        ]
+        .into_iter()
+        .find_map(|(set, what)| set.then_some(what));
+        if let Some(what) = conflict {
+            return Err(syn::Error::new(
+                span,
+                format!("`const_fn` cannot be combined with {what}: `from_const` moves the fields out of a single owned source"),
+            ));
+        }
+    }
+    if (kind == ImplKind::TryFrom || (kind == ImplKind::From && fallible)) && !seen_error {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "Expected `#[dto(error = Type)]` on the struct.",
+        ));
+    }
+    Ok(StructAttrs {
+        shared: shared > 0,
+        sources,
+        error,
+        generate_error,
+        by_ref,
+        both_refs,
+        fallible,
+        bounds,
+        rename_all,
+        strip_prefix,
+        reverse_default,
+        fill_default,
+        only_annotated,
+        no_inline,
+        collection_helper,
+        inherent,
+        const_fn,
+        post_build,
+        debug_assert_invariant,
+        exhaustive,
+        auto_nested,
+        generics_from_source,
+        debug,
+        cfg_feature,
     })
 }
+
+/// Points `self` in a `debug_assert_invariant` expression at the `__dto_out` being returned.
+/// A `self::` path prefix names the current module and is kept.
+fn self_to_out(tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    use proc_macro2::{Group, TokenTree};
+    let trees: Vec<TokenTree> = tokens.into_iter().collect();
+    let starts_path = |i: usize| {
+        matches!(
+            (trees.get(i), trees.get(i + 1)),
+            (Some(TokenTree::Punct(a)), Some(TokenTree::Punct(b))) if a.as_char() == ':' && b.as_char() == ':'
+        )
+    };
+    trees
+        .iter()
+        .enumerate()
+        .map(|(i, tree)| match tree {
+            TokenTree::Ident(ident) if ident == "self" && !starts_path(i + 1) => {
+                TokenTree::Ident(Ident::new("__dto_out", ident.span()))
+            }
+            TokenTree::Group(group) => {
+                let mut inner = Group::new(group.delimiter(), self_to_out(group.stream()));
+                inner.set_span(group.span());
+                TokenTree::Group(inner)
+            }
+            other => other.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_str(input: proc_macro2::TokenStream) -> String {
+        let input: DeriveInput = syn::parse2(input).unwrap();
+        expand(&input, ImplKind::From).unwrap().to_string()
+    }
+
+    #[test]
+    fn generated_from_is_inline_by_default() {
+        let out = expand_str(quote! {
+            #[dto(from = Source)]
+            struct Dto { id: u32 }
+        });
+        assert!(out.contains("# [inline] fn from"), "{out}");
+    }
+
+    #[test]
+    fn cfg_attributes_are_forwarded_to_the_initializer() {
+        let out = expand_str(quote! {
+            #[dto(from = Source)]
+            struct Dto {
+                id: u32,
+                #[cfg(feature = "audit")]
+                #[dto(rename = "actor", clone)]
+                audited_by: String,
+            }
+        });
+        assert!(
+            out.contains("# [cfg (feature = \"audit\")] let __dto_field_audited_by"),
+            "{out}"
+        );
+        assert!(
+            out.contains("# [cfg (feature = \"audit\")] audited_by : __dto_field_audited_by"),
+            "{out}"
+        );
+    }
+
+    #[test]
+    fn debug_does_not_change_the_expansion() {
+        let plain = expand_str(quote! {
+            #[dto(from = Source)]
+            struct Dto { id: u32 }
+        });
+        let debugged = expand_str(quote! {
+            #[dto(from = Source, debug)]
+            struct Dto { id: u32 }
+        });
+        assert_eq!(plain, debugged);
+    }
+
+    #[test]
+    fn direct_is_the_default_mapping() {
+        let plain = expand_str(quote! {
+            #[dto(from = Source)]
+            struct Dto { id: u32 }
+        });
+        let marked = expand_str(quote! {
+            #[dto(from = Source, only_annotated)]
+            struct Dto { #[dto(direct)] id: u32 }
+        });
+        assert_eq!(plain, marked);
+    }
+
+    #[test]
+    fn cfg_feature_gates_every_generated_item() {
+        let out = expand_str(quote! {
+            #[dto(from = Source, cfg_feature = "serde", inherent)]
+            struct Dto { id: u32 }
+        });
+        assert_eq!(
+            out.matches("# [cfg (feature = \"serde\")]").count(),
+            2,
+            "{out}"
+        );
+    }
+
+    #[test]
+    fn generated_impls_are_marked_automatically_derived() {
+        let out = expand_str(quote! {
+            #[dto(from = Source, collection_helper)]
+            struct Dto { id: u32 }
+        });
+        assert_eq!(out.matches("# [automatically_derived]").count(), 2, "{out}");
+    }
+
+    #[test]
+    fn rename_all_converts_between_cases() {
+        assert_eq!(RenameRule::Snake.apply("userId"), "user_id");
+        assert_eq!(RenameRule::Camel.apply("user_id"), "userId");
+        assert_eq!(RenameRule::Pascal.apply("user_id"), "UserId");
+        assert_eq!(RenameRule::ScreamingSnake.apply("userId"), "USER_ID");
+        assert_eq!(RenameRule::Lower.apply("user_id"), "userid");
+        assert_eq!(RenameRule::Upper.apply("userId"), "USERID");
+        assert_eq!(RenameRule::Camel.apply("line2_total"), "line2Total");
+    }
+
+    #[test]
+    fn no_inline_drops_the_attribute() {
+        let out = expand_str(quote! {
+            #[dto(from = Source, no_inline)]
+            struct Dto { id: u32 }
+        });
+        assert!(!out.contains("inline"), "{out}");
+    }
+
+    #[test]
+    fn turbofish_from_is_the_same_source() {
+        let input: DeriveInput = syn::parse2(quote! {
+            #[dto(from = Page<u8>, from = Page::<u8>)]
+            struct Dto { total: u64 }
+        })
+        .unwrap();
+        let err = expand(&input, ImplKind::From).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "duplicate `from` on struct: `Page<u8>` is already a source"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Integration test for the struct-level `#[dto(by_ref)]` mode.
+//!
+//! Covers:
+//! - `impl From<&Source>` leaving the source usable after conversion
+//! - Direct and `into` fields cloned out of the borrowed source
+//! - `transform_fn` receiving `&SourceFieldType`
+//! - `skip` fields still default-initialized
+//! - `from = &'a Source` as a by-ref source with an explicit lifetime
+//! - Smart-pointer sources such as `Arc<Source>` read through `clone`
+//! - `#[dto(both_refs)]` generating `From<Source>` and `From<&Source>` together
+
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    #[derive(Debug, Clone)]
+    pub enum SourceStatus {
+        Active,
+        Inactive,
+    }
+
+    #[derive(Debug)]
+    pub struct Source {
+        pub id: String,
+        pub name: String,
+        pub tags: Vec<String>,
+        pub status: SourceStatus,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum DtoStatus {
+        Active,
+        Inactive,
+    }
+
+    impl From<SourceStatus> for DtoStatus {
+        fn from(s: SourceStatus) -> Self {
+            match s {
+                SourceStatus::Active => DtoStatus::Active,
+                SourceStatus::Inactive => DtoStatus::Inactive,
+            }
+        }
+    }
+
+    pub fn tag_count(source: &Source) -> usize {
+        source.tags.len()
+    }
+
+    // by-ref transforms borrow the source field (deref coercion applies)
+    pub fn name_len(name: &str) -> usize {
+        name.len()
+    }
+}
+
+use types::*;
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = types::Source, by_ref)]
+pub struct Dto {
+    // direct -> cloned
+    pub id: String,
+
+    // rename + borrowing transform
+    #[dto(rename = "name", transform_fn = types::name_len)]
+    pub name_len: usize,
+
+    pub tags: Vec<String>,
+
+    // into on a clone
+    #[dto(into)]
+    pub status: types::DtoStatus,
+
+    #[dto(skip)]
+    pub placeholder: Option<String>,
+}
+
+#[test]
+fn test_by_ref_mapping_keeps_source() {
+    let src = Source {
+        id: "u1".into(),
+        name: "Alice".into(),
+        tags: vec!["a".into(), "b".into()],
+        status: SourceStatus::Active,
+    };
+
+    let dto: Dto = (&src).into();
+
+    assert_eq!(dto.id, "u1");
+    assert_eq!(dto.name_len, 5);
+    assert_eq!(dto.tags, vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(dto.status, DtoStatus::Active);
+    assert_eq!(dto.placeholder, None);
+
+    // the source is still owned by the caller
+    assert_eq!(src.name, "Alice");
+    let again = Dto::from(&src);
+    assert_eq!(again.id, dto.id);
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = &'a types::Source)]
+pub struct BorrowedDto {
+    pub id: String,
+
+    #[dto(rename = "name", transform_fn = types::name_len)]
+    pub name_len: usize,
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = std::sync::Arc<types::Source>)]
+pub struct SharedDto {
+    #[dto(clone)]
+    pub id: String,
+
+    #[dto(clone)]
+    pub tags: Vec<String>,
+}
+
+#[test]
+fn test_reference_and_arc_sources() {
+    let src = Source {
+        id: "u2".into(),
+        name: "Bob".into(),
+        tags: vec!["t".into()],
+        status: SourceStatus::Inactive,
+    };
+
+    let borrowed = BorrowedDto::from(&src);
+    assert_eq!(borrowed.id, "u2");
+    assert_eq!(borrowed.name_len, 3);
+
+    let shared = SharedDto::from(std::sync::Arc::new(src));
+    assert_eq!(shared.id, "u2");
+    assert_eq!(shared.tags, vec!["t".to_string()]);
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = types::Source, both_refs)]
+pub struct EitherDto {
+    pub id: String,
+
+    #[dto(into)]
+    pub status: types::DtoStatus,
+
+    #[dto(transform_fn = types::tag_count, from_source)]
+    pub tag_count: usize,
+
+    #[dto(rename = "name", transform_expr = |n| n.len())]
+    pub name_len: usize,
+}
+
+#[test]
+fn test_both_refs_generates_owned_and_borrowed_impls() {
+    let src = Source {
+        id: "u3".into(),
+        name: "Cleo".into(),
+        tags: vec!["a".into(), "b".into()],
+        status: SourceStatus::Active,
+    };
+
+    let borrowed = EitherDto::from(&src);
+    let owned = EitherDto::from(src);
+
+    for dto in [borrowed, owned] {
+        assert_eq!(dto.id, "u3");
+        assert_eq!(dto.status, DtoStatus::Active);
+        assert_eq!(dto.tag_count, 2);
+        assert_eq!(dto.name_len, 4);
+    }
+}
@@ -0,0 +1,70 @@
+//! Integration test for the `chrono` feature's timestamp conversions.
+//!
+//! Covers:
+//! - `#[dto(naive_to_utc)]` reading a `NaiveDateTime` as UTC into `DateTime<Utc>`
+//! - `#[dto(utc_to_naive)]` dropping the offset of a `DateTime<Utc>`
+//! - `Option` fields converted element-wise
+//! - `DtoInto` applying the opposite conversion
+#![cfg(feature = "chrono")]
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use simple_dto_mapper_derive::{DtoFrom, DtoInto};
+
+mod types {
+    use chrono::{DateTime, NaiveDateTime, Utc};
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Post {
+        pub created_at: NaiveDateTime,
+        pub published_at: Option<NaiveDateTime>,
+        pub synced_at: DateTime<Utc>,
+    }
+}
+
+#[derive(Debug, DtoFrom, DtoInto)]
+#[dto(from = types::Post)]
+pub struct PostDto {
+    #[dto(naive_to_utc)]
+    pub created_at: DateTime<Utc>,
+
+    #[dto(naive_to_utc)]
+    pub published_at: Option<DateTime<Utc>>,
+
+    #[dto(utc_to_naive)]
+    pub synced_at: NaiveDateTime,
+}
+
+fn at(hour: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2024, 5, 1)
+        .unwrap()
+        .and_hms_opt(hour, 30, 0)
+        .unwrap()
+}
+
+#[test]
+fn test_naive_and_utc_conversions() {
+    let post = types::Post {
+        created_at: at(8),
+        published_at: Some(at(9)),
+        synced_at: at(10).and_utc(),
+    };
+
+    let dto: PostDto = post.clone().into();
+    assert_eq!(dto.created_at, at(8).and_utc());
+    assert_eq!(dto.published_at, Some(at(9).and_utc()));
+    assert_eq!(dto.synced_at, at(10));
+
+    let back: types::Post = dto.into();
+    assert_eq!(back, post);
+}
+
+#[test]
+fn test_none_stays_none() {
+    let dto: PostDto = types::Post {
+        created_at: at(1),
+        published_at: None,
+        synced_at: at(2).and_utc(),
+    }
+    .into();
+    assert_eq!(dto.published_at, None);
+}
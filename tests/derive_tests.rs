@@ -107,3 +107,2530 @@ fn test_basic_mapping() {
     assert_eq!(dto.status, DtoStatus::Active);
     assert_eq!(dto.tag_lengths, vec![2, 4, 3]);
 }
+
+mod defaults {
+    // Deliberately not `Default`: `#[dto(default = ...)]` must not require it.
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Label(pub String);
+
+    pub struct Source {
+        pub id: String,
+    }
+
+    pub fn placeholder() -> Label {
+        Label("pending".into())
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = defaults::Source)]
+pub struct DefaultsDto {
+    pub id: String,
+
+    // custom initializer instead of Default::default()
+    #[dto(default = defaults::Label("N/A".to_string()))]
+    pub label: defaults::Label,
+
+    #[dto(default = Vec::with_capacity(16))]
+    pub buffer: Vec<u8>,
+
+    // bare `default` is `skip`
+    #[dto(default)]
+    pub retries: u8,
+
+    #[dto(with_default_fn = defaults::placeholder)]
+    pub status: defaults::Label,
+}
+
+#[test]
+fn test_default_expr_on_skipped_fields() {
+    let dto: DefaultsDto = defaults::Source { id: "u2".into() }.into();
+
+    assert_eq!(dto.id, "u2");
+    assert_eq!(dto.label, defaults::Label("N/A".into()));
+    assert!(dto.buffer.is_empty());
+    assert!(dto.buffer.capacity() >= 16);
+    assert_eq!(dto.retries, 0);
+    assert_eq!(dto.status, defaults::Label("pending".into()));
+}
+
+mod auto {
+    #[derive(Debug)]
+    pub struct SourceTag(pub String);
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct DtoTag(pub String);
+
+    impl From<SourceTag> for DtoTag {
+        fn from(t: SourceTag) -> Self {
+            DtoTag(t.0)
+        }
+    }
+
+    pub struct Source {
+        pub tags: Vec<SourceTag>,
+        pub primary: Option<SourceTag>,
+        pub missing: Option<SourceTag>,
+        pub single: SourceTag,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = auto::Source)]
+pub struct AutoIntoDto {
+    // Vec<SourceTag> -> Vec<DtoTag>
+    #[dto(auto_into)]
+    pub tags: Vec<auto::DtoTag>,
+
+    // Option<SourceTag> -> Option<DtoTag>
+    #[dto(auto_into)]
+    pub primary: Option<auto::DtoTag>,
+
+    #[dto(auto_into)]
+    pub missing: ::std::option::Option<auto::DtoTag>,
+
+    // plain Into fallback
+    #[dto(auto_into)]
+    pub single: auto::DtoTag,
+}
+
+#[test]
+fn test_auto_into_recurses_into_vec_and_option() {
+    let src = auto::Source {
+        tags: vec![auto::SourceTag("a".into()), auto::SourceTag("b".into())],
+        primary: Some(auto::SourceTag("p".into())),
+        missing: None,
+        single: auto::SourceTag("s".into()),
+    };
+
+    let dto: AutoIntoDto = src.into();
+
+    assert_eq!(
+        dto.tags,
+        vec![auto::DtoTag("a".into()), auto::DtoTag("b".into())]
+    );
+    assert_eq!(dto.primary, Some(auto::DtoTag("p".into())));
+    assert_eq!(dto.missing, None);
+    assert_eq!(dto.single, auto::DtoTag("s".into()));
+}
+
+mod multi {
+    pub struct Account {
+        pub id: u64,
+        pub handle: String,
+        pub email: String,
+    }
+
+    pub struct Invite {
+        pub id: u64,
+        pub handle: String,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = multi::Account)]
+#[dto(from = multi::Invite)]
+pub struct MemberDto {
+    pub id: u64,
+
+    #[dto(rename = "handle")]
+    pub name: String,
+
+    #[dto(skip)]
+    pub note: Option<String>,
+}
+
+#[test]
+fn test_multiple_sources() {
+    let from_account: MemberDto = multi::Account {
+        id: 1,
+        handle: "alice".into(),
+        email: "a@example.com".into(),
+    }
+    .into();
+    let from_invite = MemberDto::from(multi::Invite {
+        id: 2,
+        handle: "bob".into(),
+    });
+
+    assert_eq!((from_account.id, from_account.name.as_str()), (1, "alice"));
+    assert_eq!((from_invite.id, from_invite.name.as_str()), (2, "bob"));
+    assert_eq!(from_invite.note, None);
+}
+
+mod tuple {
+    pub struct Account {
+        pub id: String,
+        pub age: u32,
+    }
+
+    pub struct Raw(pub String, pub u32);
+}
+
+// positional fields read the source named by `rename`
+#[derive(Debug, DtoFrom)]
+#[dto(from = tuple::Account)]
+pub struct IdDto(
+    #[dto(rename = "id")] pub String,
+    #[dto(rename = "age")] pub u32,
+);
+
+// without `rename`, position N reads `source.N`
+#[derive(Debug, DtoFrom)]
+#[dto(from = tuple::Raw)]
+pub struct RawDto(pub String, pub u32);
+
+#[test]
+fn test_tuple_struct_targets() {
+    let dto: IdDto = tuple::Account {
+        id: "u3".into(),
+        age: 7,
+    }
+    .into();
+    assert_eq!((dto.0.as_str(), dto.1), ("u3", 7));
+
+    let raw: RawDto = tuple::Raw("r".into(), 9).into();
+    assert_eq!((raw.0.as_str(), raw.1), ("r", 9));
+}
+
+mod whole {
+    pub struct Person {
+        pub first: String,
+        pub last: String,
+        pub age: u32,
+    }
+
+    pub fn full_name(p: &Person) -> String {
+        format!("{} {}", p.first, p.last)
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = whole::Person)]
+pub struct PersonDto {
+    // moves out of the source; the whole-source transform still sees every field
+    pub first: String,
+
+    #[dto(transform_fn = whole::full_name, from_source)]
+    pub full_name: String,
+
+    pub age: u32,
+}
+
+#[test]
+fn test_transform_from_whole_source() {
+    let dto: PersonDto = whole::Person {
+        first: "Ada".into(),
+        last: "Lovelace".into(),
+        age: 36,
+    }
+    .into();
+
+    assert_eq!(dto.first, "Ada");
+    assert_eq!(dto.full_name, "Ada Lovelace");
+    assert_eq!(dto.age, 36);
+}
+
+mod nested {
+    pub struct Address {
+        pub city: String,
+        pub zip: String,
+    }
+
+    pub struct User {
+        pub id: u64,
+        pub address: Address,
+    }
+
+    pub fn trimmed(s: String) -> String {
+        s.trim().to_string()
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = nested::User)]
+pub struct FlatUserDto {
+    pub id: u64,
+
+    #[dto(source_path = "address.city")]
+    pub city: String,
+
+    #[dto(source_path = "address.zip", transform_fn = nested::trimmed)]
+    pub zip: String,
+}
+
+#[test]
+fn test_source_path_reads_nested_fields() {
+    let dto: FlatUserDto = nested::User {
+        id: 5,
+        address: nested::Address {
+            city: "Seoul".into(),
+            zip: " 04524 ".into(),
+        },
+    }
+    .into();
+
+    assert_eq!(dto.id, 5);
+    assert_eq!(dto.city, "Seoul");
+    assert_eq!(dto.zip, "04524");
+}
+
+mod encapsulated {
+    pub struct Account {
+        id: u64,
+        name: String,
+    }
+
+    impl Account {
+        pub fn new(id: u64, name: &str) -> Self {
+            Account {
+                id,
+                name: name.into(),
+            }
+        }
+
+        pub fn id(&self) -> u64 {
+            self.id
+        }
+
+        pub fn name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    pub fn shout(s: &str) -> String {
+        s.to_uppercase()
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = encapsulated::Account)]
+pub struct AccountDto {
+    #[dto(getter = "id")]
+    pub id: u64,
+
+    // getter composed with transform_fn
+    #[dto(getter = "name", transform_fn = encapsulated::shout)]
+    pub loud_name: String,
+
+    // `rename` + `call` is the long form of `getter`
+    #[dto(rename = "name", call, transform_fn = str::to_owned)]
+    pub name: String,
+}
+
+#[test]
+fn test_getter_methods() {
+    let dto: AccountDto = encapsulated::Account::new(9, "carol").into();
+
+    assert_eq!(dto.id, 9);
+    assert_eq!(dto.loud_name, "CAROL");
+    assert_eq!(dto.name, "carol");
+}
+
+mod pinned {
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Meters(pub u32);
+
+    impl From<u16> for Meters {
+        fn from(v: u16) -> Self {
+            Meters(v.into())
+        }
+    }
+
+    pub type Distance = Meters;
+
+    pub struct Source {
+        pub distance: u16,
+        pub count: u16,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = pinned::Source)]
+pub struct PinnedDto {
+    // destination named explicitly, through an alias
+    #[dto(into = pinned::Meters)]
+    pub distance: pinned::Distance,
+
+    #[dto(into = u64)]
+    pub count: u64,
+}
+
+#[test]
+fn test_into_with_explicit_destination() {
+    let dto: PinnedDto = pinned::Source {
+        distance: 12,
+        count: 3,
+    }
+    .into();
+
+    assert_eq!(dto.distance, pinned::Meters(12));
+    assert_eq!(dto.count, 3);
+}
+
+mod cloned {
+    pub struct Source {
+        pub name: String,
+        pub tags: Vec<String>,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = cloned::Source)]
+pub struct ClonedDto {
+    // moves `source.name`
+    pub name: String,
+
+    // reads the same source field again; cloned before the move above
+    #[dto(rename = "name", clone)]
+    pub display_name: String,
+
+    #[dto(rename = "tags", clone, auto_into)]
+    pub first_tags: Vec<String>,
+
+    pub tags: Vec<String>,
+}
+
+#[test]
+fn test_clone_reads_a_field_twice() {
+    let dto: ClonedDto = cloned::Source {
+        name: "dave".into(),
+        tags: vec!["x".into()],
+    }
+    .into();
+
+    assert_eq!(dto.name, "dave");
+    assert_eq!(dto.display_name, "dave");
+    assert_eq!(dto.first_tags, dto.tags);
+}
+
+mod inline {
+    pub struct Source {
+        pub name: String,
+        pub scores: Vec<u32>,
+        pub first: String,
+        pub last: String,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = inline::Source)]
+pub struct InlineDto {
+    #[dto(transform_expr = |x| x.trim().to_string())]
+    pub name: String,
+
+    #[dto(rename = "scores", transform_expr = |v| v.iter().sum())]
+    pub total: u32,
+
+    #[dto(transform_expr = |s| format!("{} {}", s.first, s.last), from_source)]
+    pub full_name: String,
+}
+
+#[test]
+fn test_transform_expr_closures() {
+    let dto: InlineDto = inline::Source {
+        name: "  erin ".into(),
+        scores: vec![1, 2, 3],
+        first: "Erin".into(),
+        last: "Ng".into(),
+    }
+    .into();
+
+    assert_eq!(dto.name, "erin");
+    assert_eq!(dto.total, 6);
+    assert_eq!(dto.full_name, "Erin Ng");
+}
+
+mod maps {
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Debug)]
+    pub struct SourceVal(pub u32);
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct SourceKey(pub String);
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct DtoVal(pub u32);
+    impl From<SourceVal> for DtoVal {
+        fn from(v: SourceVal) -> Self {
+            DtoVal(v.0)
+        }
+    }
+
+    impl From<SourceKey> for String {
+        fn from(k: SourceKey) -> Self {
+            k.0
+        }
+    }
+
+    pub struct Source {
+        pub scores: HashMap<String, SourceVal>,
+        pub ranked: BTreeMap<SourceKey, SourceVal>,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = maps::Source)]
+pub struct MapsDto {
+    #[dto(map_values)]
+    pub scores: std::collections::HashMap<String, maps::DtoVal>,
+
+    #[dto(map_keys_values)]
+    pub ranked: std::collections::BTreeMap<String, maps::DtoVal>,
+}
+
+#[test]
+fn test_map_value_conversion() {
+    let dto: MapsDto = maps::Source {
+        scores: [("a".to_string(), maps::SourceVal(1))]
+            .into_iter()
+            .collect(),
+        ranked: [(maps::SourceKey("b".into()), maps::SourceVal(2))]
+            .into_iter()
+            .collect(),
+    }
+    .into();
+
+    assert_eq!(dto.scores["a"], maps::DtoVal(1));
+    assert_eq!(dto.ranked["b"], maps::DtoVal(2));
+}
+
+mod cased {
+    #[allow(non_snake_case)]
+    pub struct Source {
+        pub userId: u32,
+        pub displayName: String,
+        pub legacy_email: String,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = cased::Source, rename_all = "camelCase")]
+pub struct CasedDto {
+    pub user_id: u32,
+    pub display_name: String,
+
+    // an explicit `rename` wins over `rename_all`
+    #[dto(rename = "legacy_email")]
+    pub email: String,
+}
+
+#[test]
+fn test_rename_all_derives_source_names() {
+    let dto: CasedDto = cased::Source {
+        userId: 7,
+        displayName: "Fay".into(),
+        legacy_email: "fay@example.com".into(),
+    }
+    .into();
+
+    assert_eq!(dto.user_id, 7);
+    assert_eq!(dto.display_name, "Fay");
+    assert_eq!(dto.email, "fay@example.com");
+}
+
+mod boxed {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Payload(pub String);
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct DtoPayload(pub String);
+    impl From<Payload> for DtoPayload {
+        fn from(p: Payload) -> Self {
+            DtoPayload(p.0)
+        }
+    }
+
+    pub struct Source {
+        pub payload: Box<Payload>,
+        pub converted: Box<Payload>,
+        pub child: Payload,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = boxed::Source)]
+pub struct BoxedDto {
+    #[dto(unbox)]
+    pub payload: boxed::Payload,
+
+    // unbox, convert, and box again
+    #[dto(unbox, into, box)]
+    pub converted: Box<boxed::DtoPayload>,
+
+    #[dto(box)]
+    pub child: Box<boxed::Payload>,
+}
+
+#[test]
+fn test_box_and_unbox() {
+    let dto: BoxedDto = boxed::Source {
+        payload: Box::new(boxed::Payload("p".into())),
+        converted: Box::new(boxed::Payload("c".into())),
+        child: boxed::Payload("k".into()),
+    }
+    .into();
+
+    assert_eq!(dto.payload, boxed::Payload("p".into()));
+    assert_eq!(*dto.converted, boxed::DtoPayload("c".into()));
+    assert_eq!(*dto.child, boxed::Payload("k".into()));
+}
+
+mod listed {
+    pub struct Source {
+        pub id: u32,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, DtoFrom)]
+#[dto(from = listed::Source, collection_helper)]
+pub struct ListedDto {
+    pub id: u32,
+}
+
+#[test]
+fn test_collection_helper_from_vec() {
+    let dtos = ListedDto::from_vec(vec![listed::Source { id: 1 }, listed::Source { id: 2 }]);
+    assert_eq!(dtos, vec![ListedDto { id: 1 }, ListedDto { id: 2 }]);
+}
+
+#[test]
+fn test_collection_helper_map_all() {
+    let from_array = ListedDto::map_all([listed::Source { id: 1 }, listed::Source { id: 2 }]);
+    assert_eq!(from_array, vec![ListedDto { id: 1 }, ListedDto { id: 2 }]);
+
+    let from_vec = ListedDto::map_all(vec![listed::Source { id: 3 }]);
+    assert_eq!(from_vec, vec![ListedDto { id: 3 }]);
+
+    let from_iter = ListedDto::map_all((4..6).map(|id| listed::Source { id }));
+    assert_eq!(from_iter, vec![ListedDto { id: 4 }, ListedDto { id: 5 }]);
+}
+
+mod renamed_into {
+    #[derive(Debug)]
+    pub enum RawStatus {
+        On,
+        Off,
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum Status {
+        Enabled,
+        Disabled,
+    }
+
+    impl From<RawStatus> for Status {
+        fn from(s: RawStatus) -> Self {
+            match s {
+                RawStatus::On => Status::Enabled,
+                RawStatus::Off => Status::Disabled,
+            }
+        }
+    }
+
+    pub struct Source {
+        pub raw_status: RawStatus,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = renamed_into::Source)]
+pub struct RenamedIntoDto {
+    #[dto(rename = "raw_status", into)]
+    pub status: renamed_into::Status,
+}
+
+#[test]
+fn test_rename_with_into() {
+    let dto: RenamedIntoDto = renamed_into::Source {
+        raw_status: renamed_into::RawStatus::Off,
+    }
+    .into();
+
+    assert_eq!(dto.status, renamed_into::Status::Disabled);
+}
+
+mod optional {
+    pub struct Source {
+        pub nickname: Option<String>,
+        pub retries: Option<u32>,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = optional::Source)]
+pub struct OptionalDto {
+    #[dto(unwrap_or = "anonymous".to_string())]
+    pub nickname: String,
+
+    #[dto(unwrap_or_default)]
+    pub retries: u32,
+}
+
+#[test]
+fn test_unwrap_optional_sources() {
+    let dto: OptionalDto = optional::Source {
+        nickname: None,
+        retries: None,
+    }
+    .into();
+    assert_eq!(dto.nickname, "anonymous");
+    assert_eq!(dto.retries, 0);
+
+    let dto: OptionalDto = optional::Source {
+        nickname: Some("gus".into()),
+        retries: Some(3),
+    }
+    .into();
+    assert_eq!(dto.nickname, "gus");
+    assert_eq!(dto.retries, 3);
+}
+
+mod shapes {
+    pub enum Shape {
+        Circle { radius: f64 },
+        Square(f64),
+    }
+
+    pub fn kind(shape: &Shape) -> &'static str {
+        match shape {
+            Shape::Circle { .. } => "circle",
+            Shape::Square(_) => "square",
+        }
+    }
+
+    pub fn area(shape: &Shape) -> f64 {
+        match shape {
+            Shape::Circle { radius } => 3.0 * radius * radius,
+            Shape::Square(side) => side * side,
+        }
+    }
+}
+
+// An enum source works as long as no field reads a source field directly.
+#[derive(Debug, DtoFrom)]
+#[dto(from = shapes::Shape)]
+pub struct ShapeDto {
+    #[dto(transform_fn = shapes::kind, from_source)]
+    pub kind: &'static str,
+
+    #[dto(transform_fn = shapes::area, from_source)]
+    pub area: f64,
+
+    #[dto(skip)]
+    pub label: Option<String>,
+}
+
+#[test]
+fn test_enum_source_with_whole_source_transforms() {
+    let dto: ShapeDto = shapes::Shape::Square(2.0).into();
+    assert_eq!(dto.kind, "square");
+    assert_eq!(dto.area, 4.0);
+    assert_eq!(dto.label, None);
+
+    let dto: ShapeDto = shapes::Shape::Circle { radius: 1.0 }.into();
+    assert_eq!(dto.kind, "circle");
+}
+
+mod asserted {
+    pub struct Source {
+        pub id: u64,
+        pub name: String,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = asserted::Source)]
+pub struct AssertedDto {
+    #[dto(assert_type = u64)]
+    pub id: u64,
+
+    #[dto(assert_type = String, transform_expr = |n| n.len())]
+    pub name: usize,
+}
+
+#[test]
+fn test_assert_type_guards_compile() {
+    let dto: AssertedDto = asserted::Source {
+        id: 9,
+        name: "hal".into(),
+    }
+    .into();
+
+    assert_eq!(dto.id, 9);
+    assert_eq!(dto.name, 3);
+}
+
+mod cows {
+    pub struct Source {
+        pub title: String,
+        pub bytes: Vec<u8>,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = cows::Source)]
+pub struct OwnedCowDto {
+    #[dto(cow)]
+    pub title: std::borrow::Cow<'static, str>,
+
+    #[dto(cow)]
+    pub bytes: std::borrow::Cow<'static, [u8]>,
+}
+
+// borrowing from the source needs the DTO lifetime tied to the reference
+#[derive(Debug, DtoFrom)]
+#[dto(from = &'a cows::Source)]
+pub struct BorrowedCowDto<'a> {
+    #[dto(cow)]
+    pub title: std::borrow::Cow<'a, str>,
+}
+
+#[test]
+fn test_cow_fields() {
+    let src = cows::Source {
+        title: "zine".into(),
+        bytes: vec![1, 2],
+    };
+
+    let borrowed = BorrowedCowDto::from(&src);
+    assert!(matches!(borrowed.title, std::borrow::Cow::Borrowed("zine")));
+
+    let owned: OwnedCowDto = src.into();
+    assert!(matches!(owned.title, std::borrow::Cow::Owned(ref t) if t == "zine"));
+    assert_eq!(&*owned.bytes, &[1, 2]);
+}
+
+mod borrowing {
+    pub struct Source {
+        pub name: String,
+    }
+
+    pub fn char_count(s: &str) -> usize {
+        s.chars().count()
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = borrowing::Source)]
+pub struct BorrowingDto {
+    // borrows `source.name` before the field below moves it
+    #[dto(rename = "name", transform_fn = borrowing::char_count, ref)]
+    pub name_len: usize,
+
+    pub name: String,
+
+    #[dto(rename = "name", transform_expr = |s: &String| s.is_empty(), ref)]
+    pub name_empty: bool,
+}
+
+#[test]
+fn test_ref_transform_borrows_source_field() {
+    let dto: BorrowingDto = borrowing::Source {
+        name: "ünï".into()
+    }
+    .into();
+
+    assert_eq!(dto.name_len, 3);
+    assert_eq!(dto.name, "ünï");
+    assert!(!dto.name_empty);
+}
+
+mod positional {
+    pub struct Raw(pub String, pub u32);
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = positional::Raw)]
+pub struct PositionalDto {
+    #[dto(index = 0)]
+    pub name: String,
+
+    #[dto(index = 1, into)]
+    pub count: u64,
+}
+
+#[test]
+fn test_index_reads_tuple_source() {
+    let dto: PositionalDto = positional::Raw("raw".into(), 4).into();
+
+    assert_eq!(dto.name, "raw");
+    assert_eq!(dto.count, 4);
+}
+
+mod collected {
+    pub struct Tag(pub String);
+
+    impl From<Tag> for String {
+        fn from(t: Tag) -> Self {
+            t.0
+        }
+    }
+
+    pub struct Source {
+        pub tags: Vec<Tag>,
+        pub ids: Vec<u32>,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = collected::Source)]
+pub struct CollectedDto {
+    #[dto(collect)]
+    pub tags: std::collections::BTreeSet<String>,
+
+    #[dto(rename = "ids", collect)]
+    pub queue: std::collections::VecDeque<u64>,
+}
+
+#[test]
+fn test_collect_changes_collection_type() {
+    let dto: CollectedDto = collected::Source {
+        tags: vec![
+            collected::Tag("b".into()),
+            collected::Tag("a".into()),
+            collected::Tag("b".into()),
+        ],
+        ids: vec![3, 1],
+    }
+    .into();
+
+    assert_eq!(dto.tags.into_iter().collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(dto.queue, [3, 1]);
+}
+
+mod finished {
+    pub struct Order {
+        pub net: u32,
+        pub tax: u32,
+        pub label: String,
+    }
+
+    pub fn total(dto: &mut super::OrderDto, order: &Order) {
+        dto.gross = order.net + order.tax;
+    }
+
+    pub fn upper_label(dto: &mut super::LabelDto, order: &Order) {
+        dto.label = format!("{} ({})", order.label.to_uppercase(), dto.net);
+    }
+}
+
+// `Copy` and cloned fields leave the owned source whole for the hook
+#[derive(Debug, DtoFrom)]
+#[dto(from = finished::Order, post_build = finished::total)]
+pub struct OrderDto {
+    pub net: u32,
+
+    #[dto(clone)]
+    pub label: String,
+
+    #[dto(default = 0)]
+    pub gross: u32,
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = finished::Order, by_ref, post_build = finished::upper_label)]
+pub struct LabelDto {
+    pub net: u32,
+    pub label: String,
+}
+
+#[test]
+fn test_post_build_hook() {
+    let order = finished::Order {
+        net: 10,
+        tax: 2,
+        label: "box".into(),
+    };
+
+    let labelled = LabelDto::from(&order);
+    assert_eq!(labelled.label, "BOX (10)");
+
+    let dto: OrderDto = order.into();
+    assert_eq!(dto.gross, 12);
+    assert_eq!(dto.label, "box");
+}
+
+mod parsed {
+    #[derive(Debug, Default, PartialEq, Eq)]
+    pub enum Status {
+        #[default]
+        Unknown,
+        Active,
+    }
+
+    impl std::str::FromStr for Status {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "active" => Ok(Status::Active),
+                _ => Err(()),
+            }
+        }
+    }
+
+    pub struct Source {
+        pub status: String,
+        pub fallback: &'static str,
+        pub port: String,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = parsed::Source)]
+pub struct ParsedDto {
+    #[dto(from_str)]
+    pub status: parsed::Status,
+
+    #[dto(from_str_or_default)]
+    pub fallback: parsed::Status,
+
+    #[dto(from_str)]
+    pub port: u16,
+}
+
+#[test]
+fn test_from_str_parses_strings() {
+    let source = || parsed::Source {
+        status: "active".into(),
+        fallback: "paused",
+        port: "8080".into(),
+    };
+
+    let dto: ParsedDto = source().into();
+    assert_eq!(dto.status, parsed::Status::Active);
+    assert_eq!(dto.fallback, parsed::Status::Unknown);
+    assert_eq!(dto.port, 8080);
+
+    let bad = std::panic::catch_unwind(|| {
+        ParsedDto::from(parsed::Source {
+            port: "http".into(),
+            ..source()
+        })
+    });
+    assert!(bad.is_err());
+}
+
+mod normalized {
+    pub struct Source {
+        pub email: String,
+    }
+
+    pub fn normalize(s: &mut String) {
+        *s = s.trim().to_lowercase();
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = normalized::Source)]
+pub struct NormalizedDto {
+    #[dto(transform_mut = normalized::normalize)]
+    pub email: String,
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = normalized::Source, by_ref)]
+pub struct NormalizedRefDto {
+    #[dto(transform_mut = normalized::normalize)]
+    pub email: String,
+}
+
+#[test]
+fn test_transform_mut_normalizes_in_place() {
+    let src = normalized::Source {
+        email: "  Ada@Example.COM ".into(),
+    };
+
+    assert_eq!(NormalizedRefDto::from(&src).email, "ada@example.com");
+
+    let dto: NormalizedDto = src.into();
+    assert_eq!(dto.email, "ada@example.com");
+}
+
+mod audited {
+    pub struct Account {
+        pub id: u32,
+        pub owner: Owner,
+        pub nickname: String,
+        pub note: Option<String>,
+    }
+
+    pub struct Owner {
+        pub name: String,
+    }
+}
+
+// every `Account` field is read: directly, renamed, or through a `source_path`
+#[derive(Debug, DtoFrom)]
+#[dto(from = audited::Account, exhaustive)]
+pub struct AuditedDto {
+    pub id: u32,
+
+    #[dto(source_path = "owner.name")]
+    pub owner_name: String,
+
+    #[dto(rename = "nickname")]
+    pub handle: String,
+
+    pub note: Option<String>,
+
+    #[dto(skip)]
+    pub checked: bool,
+}
+
+#[test]
+fn test_exhaustive_mapping_compiles() {
+    let dto: AuditedDto = audited::Account {
+        id: 1,
+        owner: audited::Owner { name: "Ada".into() },
+        nickname: "ada".into(),
+        note: None,
+    }
+    .into();
+
+    assert_eq!(dto.owner_name, "Ada");
+    assert_eq!(dto.handle, "ada");
+}
+
+mod nested_auto {
+    pub struct Address {
+        pub city: String,
+    }
+
+    pub struct Person {
+        pub name: String,
+        pub home: Address,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = nested_auto::Address)]
+pub struct AddressDto {
+    pub city: String,
+}
+
+// `home` converts through `AddressDto: From<Address>`, `name` through the reflexive `From<String>`
+#[derive(Debug, DtoFrom)]
+#[dto(from = nested_auto::Person, auto_nested)]
+pub struct ResidentDto {
+    pub name: String,
+    pub home: AddressDto,
+}
+
+#[test]
+fn test_auto_nested_converts_direct_fields() {
+    let dto: ResidentDto = nested_auto::Person {
+        name: "Ada".into(),
+        home: nested_auto::Address {
+            city: "London".into(),
+        },
+    }
+    .into();
+
+    assert_eq!(dto.name, "Ada");
+    assert_eq!(dto.home.city, "London");
+}
+
+mod truncating {
+    pub struct Post {
+        pub title: String,
+        pub body: String,
+    }
+
+    pub fn truncate(s: String, max: usize) -> String {
+        s.chars().take(max).collect()
+    }
+
+    pub fn excerpt(post: &Post, max: usize, ellipsis: &str) -> String {
+        format!("{}{ellipsis}", truncate(post.body.clone(), max))
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = truncating::Post)]
+pub struct PostPreviewDto {
+    #[dto(transform_fn = truncating::truncate, args(5))]
+    pub title: String,
+
+    #[dto(transform_fn = truncating::excerpt, args(3, "..."), from_source)]
+    pub excerpt: String,
+}
+
+#[test]
+fn test_transform_fn_args_are_appended() {
+    let dto: PostPreviewDto = truncating::Post {
+        title: "Release notes".into(),
+        body: "Hello world".into(),
+    }
+    .into();
+
+    assert_eq!(dto.title, "Relea");
+    assert_eq!(dto.excerpt, "Hel...");
+}
+
+mod wrapped {
+    pub struct Ticket {
+        pub status: Option<super::SourceStatus>,
+        pub history: Vec<super::SourceStatus>,
+    }
+}
+
+// `into` sees the `Option` / `Vec` target and converts the inner values
+#[derive(Debug, DtoFrom)]
+#[dto(from = wrapped::Ticket)]
+pub struct TicketDto {
+    #[dto(into)]
+    pub status: Option<DtoStatus>,
+
+    #[dto(into)]
+    pub history: Vec<DtoStatus>,
+}
+
+#[test]
+fn test_into_converts_option_and_vec_elements() {
+    let dto: TicketDto = wrapped::Ticket {
+        status: Some(SourceStatus::Inactive),
+        history: vec![SourceStatus::Active, SourceStatus::Inactive],
+    }
+    .into();
+
+    assert_eq!(dto.status, Some(DtoStatus::Inactive));
+    assert_eq!(dto.history, [DtoStatus::Active, DtoStatus::Inactive]);
+}
+
+mod raw_note {
+    pub struct Note {
+        pub title: String,
+        pub body: String,
+    }
+}
+
+// `into = Type` keeps the whole-value conversion a bare `into` used to do
+#[derive(Debug, DtoFrom)]
+#[dto(from = raw_note::Note)]
+pub struct NoteDto {
+    #[dto(into = Option<String>)]
+    pub title: Option<String>,
+
+    #[dto(into = Vec<u8>)]
+    pub body: Vec<u8>,
+}
+
+#[test]
+fn test_pinned_into_converts_the_whole_value() {
+    let dto: NoteDto = raw_note::Note {
+        title: "todo".into(),
+        body: "hi".into(),
+    }
+    .into();
+
+    assert_eq!(dto.title, Some("todo".to_string()));
+    assert_eq!(dto.body, b"hi");
+}
+
+mod attribute_bag {
+    use std::collections::{BTreeMap, HashMap};
+
+    pub struct Record {
+        pub id: u32,
+        pub attributes: HashMap<String, String>,
+        pub limits: BTreeMap<&'static str, u32>,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = attribute_bag::Record)]
+pub struct RecordDto {
+    pub id: u32,
+
+    #[dto(from_map = "attributes", key = "email")]
+    pub contact: String,
+
+    // the entry composes with the value conversions
+    #[dto(from_map = "attributes", key = "age", from_str_or_default)]
+    pub age: u8,
+
+    // without `key`, the field name is the entry looked up
+    #[dto(from_map = "limits")]
+    pub retries: u32,
+}
+
+#[test]
+fn test_from_map_reads_entries_by_key() {
+    let dto: RecordDto = attribute_bag::Record {
+        id: 7,
+        attributes: [("email", "ada@example.com"), ("age", "36")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        limits: [("retries", 3)].into_iter().collect(),
+    }
+    .into();
+    assert_eq!(dto.id, 7);
+    assert_eq!(dto.contact, "ada@example.com");
+    assert_eq!(dto.age, 36);
+    assert_eq!(dto.retries, 3);
+
+    // missing entries fall back to `Default`
+    let empty: RecordDto = attribute_bag::Record {
+        id: 8,
+        attributes: Default::default(),
+        limits: Default::default(),
+    }
+    .into();
+    assert_eq!(empty.contact, "");
+    assert_eq!(empty.age, 0);
+    assert_eq!(empty.retries, 0);
+}
+
+mod numeric {
+    pub struct Reading {
+        pub level: i32,
+        pub celsius: f64,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = numeric::Reading)]
+pub struct ReadingDto {
+    #[dto(as = u8)]
+    pub level: u8,
+
+    // `as` truncates toward zero
+    #[dto(rename = "celsius", as = i64)]
+    pub whole_degrees: i64,
+}
+
+#[test]
+fn test_as_casts_numeric_fields() {
+    let dto: ReadingDto = numeric::Reading {
+        level: 7,
+        celsius: 21.9,
+    }
+    .into();
+    assert_eq!(dto.level, 7);
+    assert_eq!(dto.whole_degrees, 21);
+
+    // the cast is lossy: out-of-range values wrap
+    let wrapped: ReadingDto = numeric::Reading {
+        level: 300,
+        celsius: -0.5,
+    }
+    .into();
+    assert_eq!(wrapped.level, 44);
+    assert_eq!(wrapped.whole_degrees, 0);
+}
+
+mod gated {
+    pub struct Event {
+        pub id: u32,
+        pub trace: String,
+    }
+}
+
+// a field compiled out is not mapped, even though the source lacks it
+#[derive(Debug, DtoFrom)]
+#[dto(from = gated::Event)]
+pub struct EventDto {
+    pub id: u32,
+
+    #[cfg(not(any()))]
+    pub trace: String,
+
+    #[cfg(any())]
+    pub internal_only: u64,
+}
+
+#[test]
+fn test_cfg_gated_fields_map_only_when_present() {
+    let dto: EventDto = gated::Event {
+        id: 3,
+        trace: "t-1".into(),
+    }
+    .into();
+    assert_eq!(dto.id, 3);
+    assert_eq!(dto.trace, "t-1");
+}
+
+mod ids {
+    pub struct UserId(pub u64);
+
+    pub struct Uuid(pub String);
+
+    impl From<UserId> for Uuid {
+        fn from(id: UserId) -> Self {
+            Uuid(format!("user-{:04}", id.0))
+        }
+    }
+
+    impl From<Uuid> for String {
+        fn from(uuid: Uuid) -> Self {
+            uuid.0
+        }
+    }
+
+    pub struct Session {
+        pub user: UserId,
+    }
+}
+
+// no `From<UserId> for String`, so the conversion goes through `Uuid`
+#[derive(Debug, DtoFrom)]
+#[dto(from = ids::Session)]
+pub struct SessionDto {
+    #[dto(into_via = ids::Uuid)]
+    pub user: String,
+}
+
+#[test]
+fn test_into_via_converts_in_two_steps() {
+    let dto: SessionDto = ids::Session {
+        user: ids::UserId(42),
+    }
+    .into();
+    assert_eq!(dto.user, "user-0042");
+}
+
+mod inherent_map {
+    #[derive(Clone)]
+    pub struct Account {
+        pub id: u32,
+        pub owner: String,
+    }
+}
+
+#[derive(Debug, PartialEq, DtoFrom)]
+#[dto(from = inherent_map::Account, inherent)]
+pub struct AccountSummaryDto {
+    pub id: u32,
+    #[dto(rename = "owner")]
+    pub holder: String,
+}
+
+#[test]
+fn test_inherent_map_from_matches_from() {
+    let account = inherent_map::Account {
+        id: 9,
+        owner: "Grace".into(),
+    };
+
+    let mapped = AccountSummaryDto::map_from(account.clone());
+    assert_eq!(mapped, AccountSummaryDto::from(account));
+    assert_eq!(mapped.holder, "Grace");
+}
+
+mod shaped {
+    pub struct Profile {
+        pub bio: String,
+        pub tags: Vec<String>,
+    }
+
+    pub fn trimmed(s: String) -> String {
+        s.trim().to_string()
+    }
+
+    pub fn first(tags: Vec<String>) -> Option<String> {
+        tags.into_iter().next()
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = shaped::Profile)]
+pub struct ProfileShapeDto {
+    // `trimmed` returns `String`; the field is `Option<String>`
+    #[dto(transform_fn = shaped::trimmed, wrap_some)]
+    pub bio: Option<String>,
+
+    // `first` returns `Option<String>`; the field is `String`
+    #[dto(transform_fn = shaped::first, flatten_some)]
+    pub tags: String,
+}
+
+#[test]
+fn test_wrap_some_and_flatten_some_adapt_transform_results() {
+    let dto: ProfileShapeDto = shaped::Profile {
+        bio: "  hi  ".into(),
+        tags: vec!["rust".into(), "go".into()],
+    }
+    .into();
+    assert_eq!(dto.bio.as_deref(), Some("hi"));
+    assert_eq!(dto.tags, "rust");
+}
+
+#[test]
+#[should_panic(expected = "the transform for `tags` returned `None` (`flatten_some`)")]
+fn test_flatten_some_panics_on_none() {
+    let _: ProfileShapeDto = shaped::Profile {
+        bio: String::new(),
+        tags: Vec::new(),
+    }
+    .into();
+}
+
+mod parsed_upstream {
+    pub struct Upload {
+        pub size: Result<u64, String>,
+        pub width: Result<u32, String>,
+        pub checksum: Result<String, String>,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = parsed_upstream::Upload)]
+pub struct UploadDto {
+    #[dto(unwrap_result)]
+    pub size: u64,
+
+    #[dto(unwrap_result, unwrap_or = 640)]
+    pub width: u32,
+
+    #[dto(unwrap_result, unwrap_or_default)]
+    pub checksum: String,
+}
+
+#[test]
+fn test_unwrap_result_flattens_results() {
+    let dto: UploadDto = parsed_upstream::Upload {
+        size: Ok(2048),
+        width: Err("no width".into()),
+        checksum: Err("no checksum".into()),
+    }
+    .into();
+    assert_eq!(dto.size, 2048);
+    assert_eq!(dto.width, 640);
+    assert_eq!(dto.checksum, "");
+}
+
+#[test]
+#[should_panic(expected = "`unwrap_result`: `source.size` is an `Err`")]
+fn test_unwrap_result_panics_on_err() {
+    let _: UploadDto = parsed_upstream::Upload {
+        size: Err("truncated".into()),
+        width: Ok(1),
+        checksum: Ok("x".into()),
+    }
+    .into();
+}
+
+mod keyword_fields {
+    pub struct Token {
+        pub r#type: String,
+        pub r#match: u32,
+        pub r#ref: Option<String>,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = keyword_fields::Token)]
+pub struct TokenDto {
+    #[dto(rename = "type")]
+    pub kind: String,
+
+    #[dto(rename = "r#match")]
+    pub score: u32,
+
+    pub r#ref: Option<String>,
+}
+
+#[test]
+fn test_rename_to_keyword_field() {
+    let dto: TokenDto = keyword_fields::Token {
+        r#type: "ident".into(),
+        r#match: 7,
+        r#ref: None,
+    }
+    .into();
+    assert_eq!(dto.kind, "ident");
+    assert_eq!(dto.score, 7);
+    assert_eq!(dto.r#ref, None);
+}
+
+mod settings_upstream {
+    pub struct Settings {
+        pub theme: String,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = settings_upstream::Settings, fill_default)]
+pub struct SettingsDto {
+    pub theme: String,
+
+    #[dto(skip)]
+    pub page_size: u32,
+
+    #[dto(default = 3)]
+    pub retries: u8,
+}
+
+// The target's `Default` fills `page_size`, rather than `u32::default()`.
+impl Default for SettingsDto {
+    fn default() -> Self {
+        Self {
+            theme: "light".into(),
+            page_size: 50,
+            retries: 0,
+        }
+    }
+}
+
+#[test]
+fn test_fill_default_uses_target_default() {
+    let dto: SettingsDto = settings_upstream::Settings {
+        theme: "dark".into(),
+    }
+    .into();
+    assert_eq!(dto.theme, "dark");
+    assert_eq!(dto.page_size, 50);
+    assert_eq!(dto.retries, 3);
+}
+
+// With `only_annotated` too, the unannotated `page_size` is the target's default.
+#[derive(Debug, DtoFrom)]
+#[dto(from = settings_upstream::Settings, fill_default, only_annotated)]
+pub struct PanelDto {
+    #[dto(direct)]
+    pub theme: String,
+
+    pub page_size: u32,
+}
+
+impl Default for PanelDto {
+    fn default() -> Self {
+        Self {
+            theme: "light".into(),
+            page_size: 25,
+        }
+    }
+}
+
+#[test]
+fn test_fill_default_fills_unannotated_fields() {
+    let dto: PanelDto = settings_upstream::Settings {
+        theme: "dark".into(),
+    }
+    .into();
+    assert_eq!(dto.theme, "dark");
+    assert_eq!(dto.page_size, 25);
+}
+
+mod audited_upstream {
+    pub struct Account {
+        pub id: u64,
+        pub email: String,
+        pub secret: String,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = audited_upstream::Account, only_annotated)]
+pub struct AuditedAccountDto {
+    #[dto(direct)]
+    pub id: u64,
+
+    #[dto(rename = "email")]
+    pub contact: String,
+}
+
+#[test]
+fn test_only_annotated_with_direct_fields() {
+    let dto: AuditedAccountDto = audited_upstream::Account {
+        id: 9,
+        email: "a@b.c".into(),
+        secret: "hunter2".into(),
+    }
+    .into();
+    assert_eq!(dto.id, 9);
+    assert_eq!(dto.contact, "a@b.c");
+}
+
+mod formatters {
+    pub struct Label {
+        pub title: String,
+        pub slug: String,
+    }
+
+    fn shout(s: String) -> String {
+        s.to_uppercase()
+    }
+
+    fn dashed(s: String) -> String {
+        s.replace(' ', "-")
+    }
+
+    pub const SHOUT: fn(String) -> String = shout;
+    pub static SLUGGERS: [fn(String) -> String; 1] = [dashed];
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = formatters::Label)]
+pub struct FormattedLabelDto {
+    // a const function pointer is still a plain path
+    #[dto(transform_fn = formatters::SHOUT)]
+    pub title: String,
+
+    // any callable expression, called as `(formatters::SLUGGERS[0])(source.slug)`
+    #[dto(transform_fn = formatters::SLUGGERS[0])]
+    pub slug: String,
+}
+
+#[test]
+fn test_transform_fn_accepts_callable_expressions() {
+    let dto: FormattedLabelDto = formatters::Label {
+        title: "hello".into(),
+        slug: "a b c".into(),
+    }
+    .into();
+    assert_eq!(dto.title, "HELLO");
+    assert_eq!(dto.slug, "a-b-c");
+}
+
+mod prefixed_upstream {
+    pub struct UserRow {
+        pub user_id: u64,
+        pub user_name: String,
+        pub created: u32,
+    }
+
+    #[allow(non_snake_case)]
+    pub struct UserJson {
+        pub userId: u64,
+        pub userName: String,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = prefixed_upstream::UserRow, strip_prefix = "user_")]
+pub struct UserRowDto {
+    pub id: u64,
+    pub name: String,
+
+    // an explicit `rename` is taken as written
+    #[dto(rename = "created")]
+    pub created_at: u32,
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = prefixed_upstream::UserJson, strip_prefix = "user_", rename_all = "camelCase")]
+pub struct UserJsonDto {
+    pub id: u64,
+    pub name: String,
+}
+
+#[test]
+fn test_strip_prefix_reads_prefixed_fields() {
+    let row: UserRowDto = prefixed_upstream::UserRow {
+        user_id: 1,
+        user_name: "Ann".into(),
+        created: 20,
+    }
+    .into();
+    assert_eq!((row.id, row.name.as_str(), row.created_at), (1, "Ann", 20));
+
+    let json: UserJsonDto = prefixed_upstream::UserJson {
+        userId: 2,
+        userName: "Bo".into(),
+    }
+    .into();
+    assert_eq!((json.id, json.name.as_str()), (2, "Bo"));
+}
+
+mod tuple_record {
+    pub struct Record(pub String, pub super::types::SourceStatus, pub u32);
+}
+
+// every positional field takes the same keys as a named one
+#[derive(Debug, DtoFrom)]
+#[dto(from = tuple_record::Record)]
+pub struct TupleRecordDto(
+    #[dto(index = 2, into)] pub u64,
+    #[dto(index = 1, into)] pub types::DtoStatus,
+    #[dto(index = 0, transform_fn = str::len, ref)] pub usize,
+    #[dto(skip)] pub u32,
+);
+
+#[test]
+fn test_tuple_targets_take_per_position_keys() {
+    let dto: TupleRecordDto =
+        tuple_record::Record("four".into(), types::SourceStatus::Active, 3).into();
+    assert_eq!(dto.0, 3);
+    assert!(matches!(dto.1, types::DtoStatus::Active));
+    assert_eq!(dto.2, 4);
+    assert_eq!(dto.3, 0);
+}
+
+mod no_default {
+    #[derive(Debug, PartialEq)]
+    pub struct Handle(pub u32);
+
+    pub struct Job {
+        pub name: String,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = no_default::Job)]
+pub struct JobDto {
+    pub name: String,
+
+    // `Handle` has no `Default`; a skipped `Option` is simply `None`
+    #[dto(skip)]
+    pub handle: Option<no_default::Handle>,
+}
+
+#[test]
+fn test_skipped_option_is_none_without_default() {
+    let dto: JobDto = no_default::Job {
+        name: "build".into(),
+    }
+    .into();
+    assert_eq!(dto.name, "build");
+    assert_eq!(dto.handle, None);
+}
+
+mod shared {
+    #[derive(Debug)]
+    pub struct Profile {
+        pub handle: String,
+        pub followers: Vec<u64>,
+    }
+
+    pub fn follower_count(followers: &[u64]) -> usize {
+        followers.len()
+    }
+}
+
+// fields are cloned out of the pointer, and transforms borrow them, as with `by_ref`
+#[derive(Debug, DtoFrom)]
+#[dto(from = std::sync::Arc<shared::Profile>, exhaustive)]
+pub struct SharedProfileDto {
+    pub handle: String,
+
+    #[dto(rename = "followers", transform_fn = shared::follower_count)]
+    pub follower_count: usize,
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = std::rc::Rc<shared::Profile>)]
+pub struct LocalProfileDto {
+    pub handle: String,
+}
+
+#[test]
+fn test_arc_and_rc_sources_are_read_by_reference() {
+    let profile = std::sync::Arc::new(shared::Profile {
+        handle: "@rust".into(),
+        followers: vec![1, 2, 3],
+    });
+    let dto: SharedProfileDto = profile.clone().into();
+    assert_eq!(dto.handle, "@rust");
+    assert_eq!(dto.follower_count, 3);
+    assert_eq!(profile.followers.len(), 3);
+
+    let local: LocalProfileDto = std::rc::Rc::new(shared::Profile {
+        handle: "@local".into(),
+        followers: vec![],
+    })
+    .into();
+    assert_eq!(local.handle, "@local");
+}
+
+mod sensor {
+    #[derive(Debug, Clone, Copy)]
+    pub struct Reading {
+        pub channel: u8,
+        pub raw: i32,
+    }
+}
+
+// moves, casts and const defaults only, so `from_const` works in a `const` item
+#[derive(Debug, PartialEq, DtoFrom)]
+#[dto(from = sensor::Reading, const_fn)]
+pub struct CalibrationDto {
+    pub channel: u8,
+
+    #[dto(rename = "raw", as = i64)]
+    pub value: i64,
+
+    #[dto(default = 1)]
+    pub scale: u16,
+
+    #[dto(skip)]
+    pub label: Option<&'static str>,
+}
+
+const CALIBRATION: CalibrationDto = CalibrationDto::from_const(sensor::Reading {
+    channel: 3,
+    raw: -40,
+});
+
+#[test]
+fn test_const_fn_builds_in_const_context() {
+    assert_eq!(
+        CALIBRATION,
+        CalibrationDto {
+            channel: 3,
+            value: -40,
+            scale: 1,
+            label: None,
+        }
+    );
+    let dto: CalibrationDto = sensor::Reading { channel: 1, raw: 7 }.into();
+    assert_eq!(
+        dto,
+        CalibrationDto::from_const(sensor::Reading { channel: 1, raw: 7 })
+    );
+}
+
+mod plugins {
+    pub trait Plugin {
+        fn name(&self) -> &str;
+    }
+
+    pub struct Greeter {
+        pub greeting: String,
+    }
+
+    impl Plugin for Greeter {
+        fn name(&self) -> &str {
+            &self.greeting
+        }
+    }
+
+    pub struct Registration {
+        pub id: u32,
+        pub plugin: Greeter,
+    }
+}
+
+// the concrete plugin is boxed and coerced to the trait object
+#[derive(DtoFrom)]
+#[dto(from = plugins::Registration)]
+pub struct RegistrationDto {
+    pub id: u32,
+
+    #[dto(boxed_dyn = plugins::Plugin)]
+    pub plugin: Box<dyn plugins::Plugin>,
+}
+
+#[test]
+fn test_boxed_dyn_coerces_to_trait_object() {
+    let dto: RegistrationDto = plugins::Registration {
+        id: 9,
+        plugin: plugins::Greeter {
+            greeting: "hello".into(),
+        },
+    }
+    .into();
+    assert_eq!(dto.id, 9);
+    assert_eq!(dto.plugin.name(), "hello");
+}
+
+mod sharing {
+    #[derive(Debug, PartialEq)]
+    pub struct Settings {
+        pub theme: String,
+    }
+
+    pub struct SettingsRow {
+        pub theme: String,
+    }
+
+    impl From<SettingsRow> for Settings {
+        fn from(row: SettingsRow) -> Self {
+            Settings { theme: row.theme }
+        }
+    }
+
+    pub struct Account {
+        pub settings: SettingsRow,
+        pub avatar: Vec<u8>,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = sharing::Account)]
+pub struct SharedAccountDto {
+    #[dto(into, arc)]
+    pub settings: std::sync::Arc<sharing::Settings>,
+
+    #[dto(rc)]
+    pub avatar: std::rc::Rc<Vec<u8>>,
+}
+
+#[test]
+fn test_arc_and_rc_wrap_the_field() {
+    let dto: SharedAccountDto = sharing::Account {
+        settings: sharing::SettingsRow {
+            theme: "dark".into(),
+        },
+        avatar: vec![0xff],
+    }
+    .into();
+    assert_eq!(dto.settings.theme, "dark");
+    assert_eq!(*dto.avatar, [0xff]);
+}
+
+mod pairs {
+    pub struct Contact {
+        pub name: String,
+    }
+
+    pub struct Source {
+        pub pair: (Contact, Contact),
+    }
+}
+
+// numeric segments index into the tuple
+#[derive(Debug, DtoFrom)]
+#[dto(from = pairs::Source)]
+pub struct PairDto {
+    #[dto(source_path = "pair.0.name")]
+    pub first: String,
+
+    #[dto(source_path = "pair.1.name")]
+    pub second: String,
+}
+
+#[test]
+fn test_source_path_with_tuple_indices() {
+    let dto: PairDto = pairs::Source {
+        pair: (
+            pairs::Contact { name: "Ann".into() },
+            pairs::Contact { name: "Ben".into() },
+        ),
+    }
+    .into();
+    assert_eq!(dto.first, "Ann");
+    assert_eq!(dto.second, "Ben");
+}
+
+mod envelope {
+    pub struct Event {
+        pub id: u64,
+        pub payload: String,
+    }
+}
+
+// the only variant is filled like a struct
+#[derive(Debug, PartialEq, DtoFrom)]
+#[dto(from = envelope::Event)]
+pub enum EventEnvelope {
+    Event {
+        id: u64,
+        #[dto(rename = "payload")]
+        body: String,
+    },
+}
+
+#[test]
+fn test_single_variant_enum_target() {
+    let dto: EventEnvelope = envelope::Event {
+        id: 4,
+        payload: "ping".into(),
+    }
+    .into();
+    assert_eq!(
+        dto,
+        EventEnvelope::Event {
+            id: 4,
+            body: "ping".into(),
+        }
+    );
+}
+
+mod people {
+    pub struct Person {
+        pub first: String,
+        pub last: String,
+        pub age: u32,
+    }
+
+    pub fn join(first: String, last: String) -> String {
+        format!("{first} {last}")
+    }
+
+    pub fn initials(first: &str, last: &str, sep: char) -> String {
+        format!("{}{sep}{}", &first[..1], &last[..1])
+    }
+}
+
+// the transform's inputs are exactly the listed source fields
+#[derive(Debug, DtoFrom)]
+#[dto(from = people::Person, exhaustive)]
+pub struct NamedPersonDto {
+    #[dto(transform_fn = people::initials, with_fields("first", "last"), ref, args('.'))]
+    pub initials: String,
+
+    #[dto(transform_fn = people::join, with_fields("first", "last"))]
+    pub full_name: String,
+
+    pub age: u32,
+}
+
+#[test]
+fn test_with_fields_passes_named_source_fields() {
+    let dto: NamedPersonDto = people::Person {
+        first: "Ada".into(),
+        last: "Lovelace".into(),
+        age: 36,
+    }
+    .into();
+    assert_eq!(dto.initials, "A.L");
+    assert_eq!(dto.full_name, "Ada Lovelace");
+    assert_eq!(dto.age, 36);
+}
+
+mod ports {
+    pub struct Listener {
+        pub port: u32,
+    }
+}
+
+// the source only offers `TryFrom`, and the value is known to fit
+#[derive(Debug, DtoFrom)]
+#[dto(from = ports::Listener)]
+pub struct ListenerDto {
+    #[dto(try_into_expect = "port numbers fit in u16")]
+    pub port: u16,
+}
+
+#[test]
+fn test_try_into_expect_converts() {
+    let dto: ListenerDto = ports::Listener { port: 8080 }.into();
+    assert_eq!(dto.port, 8080);
+}
+
+#[test]
+#[should_panic(expected = "port numbers fit in u16")]
+fn test_try_into_expect_panics_with_message() {
+    let _: ListenerDto = ports::Listener { port: 70_000 }.into();
+}
+
+mod cells {
+    pub struct Counter {
+        pub hits: u32,
+        pub label: &'static str,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = cells::Counter)]
+pub struct CounterDto {
+    #[dto(cell)]
+    pub hits: std::cell::Cell<u32>,
+
+    #[dto(refcell, into)]
+    pub label: std::cell::RefCell<String>,
+}
+
+#[test]
+fn test_cell_and_refcell_wrap_the_field() {
+    let dto: CounterDto = cells::Counter {
+        hits: 3,
+        label: "visits",
+    }
+    .into();
+
+    dto.hits.set(dto.hits.get() + 1);
+    dto.label.borrow_mut().push_str(" today");
+    assert_eq!(dto.hits.get(), 4);
+    assert_eq!(*dto.label.borrow(), "visits today");
+}
+
+mod qualified {
+    pub trait Scale {
+        fn to_percent(value: f32) -> u8;
+    }
+
+    pub struct Ratio;
+
+    impl Scale for Ratio {
+        fn to_percent(value: f32) -> u8 {
+            (value * 100.0).round() as u8
+        }
+    }
+
+    pub struct Progress {
+        pub done: f32,
+        pub label: String,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = qualified::Progress)]
+pub struct ProgressDto {
+    // a trait method named through a qualified path, without a wrapper function
+    #[dto(transform_fn = <qualified::Ratio as qualified::Scale>::to_percent)]
+    pub done: u8,
+
+    #[dto(transform_fn = <str as ToString>::to_string, ref)]
+    pub label: String,
+}
+
+#[test]
+fn test_qualified_path_transform() {
+    let dto: ProgressDto = qualified::Progress {
+        done: 0.425,
+        label: "upload".into(),
+    }
+    .into();
+    assert_eq!(dto.done, 43);
+    assert_eq!(dto.label, "upload");
+}
+
+mod domain {
+    pub struct User {
+        pub id: u32,
+        pub name: String,
+    }
+}
+
+mod api {
+    use simple_dto_mapper_derive::DtoFrom;
+
+    // a unit struct named `source` would turn a `source` binding into a pattern
+    #[allow(non_camel_case_types, dead_code)]
+    struct source;
+
+    // same name as the source type, which is only reachable through its path
+    #[derive(DtoFrom)]
+    #[dto(from = crate::domain::User)]
+    pub struct User {
+        pub id: u32,
+        pub name: String,
+    }
+
+    #[derive(DtoFrom)]
+    #[dto(from = super::domain::User, by_ref)]
+    pub struct UserRef {
+        pub name: String,
+    }
+}
+
+#[test]
+fn test_same_named_source_and_target() {
+    let user = domain::User {
+        id: 7,
+        name: "Ada".into(),
+    };
+
+    let by_ref: api::UserRef = (&user).into();
+    assert_eq!(by_ref.name, "Ada");
+
+    let dto: api::User = user.into();
+    assert_eq!(dto.id, 7);
+    assert_eq!(dto.name, "Ada");
+}
+
+mod origin {
+    pub struct Reading {
+        pub source: String,
+        pub unit: String,
+    }
+}
+
+// a field named `source` whose clone is bound before the struct literal
+#[derive(DtoFrom)]
+#[dto(from = origin::Reading)]
+pub struct OriginDto {
+    #[dto(clone)]
+    pub source: String,
+    pub unit: String,
+}
+
+#[test]
+fn test_cloned_field_named_source() {
+    let dto: OriginDto = origin::Reading {
+        source: "sensor-1".into(),
+        unit: "C".into(),
+    }
+    .into();
+    assert_eq!(dto.source, "sensor-1");
+    assert_eq!(dto.unit, "C");
+}
+
+mod hygiene {
+    use simple_dto_mapper_derive::DtoFrom;
+
+    pub mod source {
+        pub struct Stock {
+            pub qty: u32,
+        }
+
+        pub fn double(qty: u32) -> u32 {
+            qty * 2
+        }
+    }
+
+    // a user const named like the generated binding, read by a default expression
+    #[allow(non_upper_case_globals)]
+    pub const source: u32 = 5;
+
+    #[derive(DtoFrom)]
+    #[dto(from = source::Stock)]
+    pub struct StockDto {
+        #[dto(transform_fn = source::double)]
+        pub qty: u32,
+
+        #[dto(skip, default = source)]
+        pub reorder_at: u32,
+    }
+}
+
+#[test]
+fn test_source_named_paths_and_consts() {
+    let dto: hygiene::StockDto = hygiene::source::Stock { qty: 4 }.into();
+    assert_eq!(dto.qty, 8);
+    assert_eq!(dto.reorder_at, hygiene::source);
+}
+
+mod codes {
+    pub struct Alert {
+        pub level: u8,
+        pub note: Option<String>,
+    }
+
+    pub fn severity(level: u8) -> &'static str {
+        if level > 2 {
+            "high"
+        } else {
+            "low"
+        }
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = codes::Alert)]
+pub struct SeverityDto {
+    // `&'static str` from the transform, then `Into::into` to the `String` field
+    #[dto(rename = "level", transform_fn = codes::severity, then_into)]
+    pub severity: String,
+
+    #[dto(transform_expr = |n: Option<String>| n.unwrap_or_default(), then_into)]
+    pub note: Box<str>,
+}
+
+#[test]
+fn test_then_into_converts_the_transform_result() {
+    let dto: SeverityDto = codes::Alert {
+        level: 3,
+        note: Some("disk".into()),
+    }
+    .into();
+    assert_eq!(dto.severity, "high");
+    assert_eq!(&*dto.note, "disk");
+}
+
+mod views {
+    pub struct Member {
+        pub name: String,
+        pub tags: Vec<String>,
+        pub age: u32,
+    }
+}
+
+// borrows from the source instead of cloning: `impl<'a> From<&'a Member> for MemberView<'a>`
+#[derive(DtoFrom)]
+#[dto(from = views::Member, by_ref)]
+pub struct MemberView<'a> {
+    pub name: &'a str,
+    pub tags: &'a [String],
+    pub age: u32,
+}
+
+#[test]
+fn test_borrowing_view_dto() {
+    let member = views::Member {
+        name: "Grace".into(),
+        tags: vec!["admin".into()],
+        age: 37,
+    };
+
+    let view = MemberView::from(&member);
+    assert_eq!(view.name, "Grace");
+    assert_eq!(view.tags, ["admin"]);
+    assert_eq!(view.age, 37);
+    // no allocation: the view points into the source
+    assert!(std::ptr::eq(view.name.as_ptr(), member.name.as_ptr()));
+    assert!(std::ptr::eq(view.tags.as_ptr(), member.tags.as_ptr()));
+}
+
+mod drafts {
+    pub struct Draft {
+        pub body: Option<String>,
+        pub title: Option<String>,
+    }
+
+    pub fn word_count(text: &str) -> usize {
+        text.split_whitespace().count()
+    }
+
+    pub fn preview(text: &str, len: usize) -> String {
+        text.chars().take(len).collect()
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = drafts::Draft)]
+pub struct DraftStatsDto {
+    // the transform reads `&String` out of the option; nothing is cloned
+    #[dto(rename = "body", transform_fn = drafts::word_count, opt_ref)]
+    pub words: Option<usize>,
+
+    #[dto(transform_fn = drafts::preview, opt_ref, args(3))]
+    pub title: Option<String>,
+}
+
+#[test]
+fn test_opt_ref_maps_over_a_borrow() {
+    let dto: DraftStatsDto = drafts::Draft {
+        body: Some("two words".into()),
+        title: None,
+    }
+    .into();
+    assert_eq!(dto.words, Some(2));
+    assert_eq!(dto.title, None);
+
+    let dto: DraftStatsDto = drafts::Draft {
+        body: None,
+        title: Some("Rustacean".into()),
+    }
+    .into();
+    assert_eq!(dto.words, None);
+    assert_eq!(dto.title.as_deref(), Some("Rus"));
+}
+
+mod spans {
+    pub struct Window {
+        pub start: u32,
+        pub end: u32,
+    }
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = spans::Window, debug_assert_invariant = "self.start <= self.end")]
+pub struct WindowDto {
+    pub start: u32,
+    pub end: u32,
+}
+
+#[test]
+fn test_debug_assert_invariant_holds() {
+    let dto: WindowDto = spans::Window { start: 1, end: 5 }.into();
+    assert_eq!((dto.start, dto.end), (1, 5));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "`WindowDto` invariant violated: self.start <= self.end")]
+fn test_debug_assert_invariant_violated() {
+    let _: WindowDto = spans::Window { start: 9, end: 2 }.into();
+}
+
+fn is_ordered(start: u32, end: u32) -> bool {
+    start <= end
+}
+
+// `self::` stays a module path; only the bare `self` names the DTO
+#[derive(Debug, DtoFrom)]
+#[dto(
+    from = spans::Window,
+    debug_assert_invariant = "self::is_ordered(self.start, self.end)"
+)]
+pub struct CheckedWindowDto {
+    pub start: u32,
+    pub end: u32,
+}
+
+#[test]
+fn test_debug_assert_invariant_with_self_path() {
+    let dto: CheckedWindowDto = spans::Window { start: 2, end: 3 }.into();
+    assert_eq!((dto.start, dto.end), (2, 3));
+}
+
+mod raw_config {
+    pub struct RawConfig {
+        pub port: String,
+        pub workers: String,
+    }
+
+    pub fn parse_port(text: String) -> Result<u16, std::num::ParseIntError> {
+        text.parse()
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = raw_config::RawConfig)]
+pub struct PortConfigDto {
+    // a `Result`-returning parser in an infallible mapping; failures become `0`
+    #[dto(transform_fn = raw_config::parse_port, or_default)]
+    pub port: u16,
+
+    #[dto(transform_expr = |w: String| w.parse::<u8>().ok(), or_default)]
+    pub workers: u8,
+}
+
+#[test]
+fn test_or_default_recovers_from_a_failed_transform() {
+    let dto: PortConfigDto = raw_config::RawConfig {
+        port: "8080".into(),
+        workers: "4".into(),
+    }
+    .into();
+    assert_eq!((dto.port, dto.workers), (8080, 4));
+
+    let dto: PortConfigDto = raw_config::RawConfig {
+        port: "http".into(),
+        workers: "lots".into(),
+    }
+    .into();
+    assert_eq!((dto.port, dto.workers), (0, 0));
+}
+
+mod dedup {
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct RawTag(pub String);
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Tag(pub String);
+
+    impl From<RawTag> for Tag {
+        fn from(raw: RawTag) -> Self {
+            Tag(raw.0)
+        }
+    }
+
+    pub struct Post {
+        pub tags: Vec<RawTag>,
+    }
+
+    pub fn sorted_unique(mut tags: Vec<RawTag>) -> Vec<RawTag> {
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = dedup::Post)]
+pub struct TaggedPostDto {
+    // the transform works on the whole `Vec`, then each element goes through `Into`
+    #[dto(transform_fn = dedup::sorted_unique, element_into)]
+    pub tags: Vec<dedup::Tag>,
+}
+
+#[test]
+fn test_element_into_after_a_collection_transform() {
+    let raw = |s: &str| dedup::RawTag(s.into());
+    let dto: TaggedPostDto = dedup::Post {
+        tags: vec![raw("rust"), raw("dto"), raw("rust")],
+    }
+    .into();
+    assert_eq!(
+        dto.tags,
+        [dedup::Tag("dto".into()), dedup::Tag("rust".into())]
+    );
+}
@@ -0,0 +1,174 @@
+//! Integration test for generic sources and DTOs.
+//!
+//! Covers:
+//! - `#[dto(from = Page<T>)]` generating `impl<T> From<Page<T>> for PageDto<T>`
+//! - Struct `where` clauses propagated onto the generated impl
+//! - `#[dto(bound = "...")]` adding bounds only the field transforms need
+//! - Sources written with turbofish (`Page::<T>`) or through a type alias
+//! - `#[dto(where = "...")]`, the same key as `bound`
+//! - `#[dto(generics_from_source)]` declaring the source's parameters on a non-generic DTO's impl
+//! - `generics_from_source` with `inherent` / `collection_helper`, whose helpers need no source bounds
+
+use simple_dto_mapper_derive::DtoFrom;
+use std::fmt::Debug;
+
+mod types {
+    pub struct Page<T> {
+        pub items: Vec<T>,
+        pub total: u64,
+    }
+
+    pub type NamePage = Page<String>;
+
+    pub fn first_cloned<T: Clone>(page: &Page<T>) -> Option<T> {
+        page.items.first().cloned()
+    }
+
+    pub fn item_count<T>(page: &Page<T>) -> usize {
+        page.items.len()
+    }
+}
+
+use types::Page;
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = Page<T>)]
+pub struct PageDto<T>
+where
+    T: Debug,
+{
+    pub items: Vec<T>,
+    pub total: u64,
+}
+
+// `T: Clone` is needed by the transform only, not by the struct definition.
+#[derive(Debug, DtoFrom)]
+#[dto(from = Page<T>, bound = "T: Clone")]
+pub struct PreviewDto<T> {
+    #[dto(transform_fn = types::first_cloned, from_source)]
+    pub first: Option<T>,
+    pub total: u64,
+}
+
+#[test]
+fn test_generic_source() {
+    let dto: PageDto<&str> = Page {
+        items: vec!["a", "b"],
+        total: 2,
+    }
+    .into();
+    assert_eq!(dto.items, vec!["a", "b"]);
+    assert_eq!(dto.total, 2);
+
+    let preview: PreviewDto<String> = Page {
+        items: vec!["x".to_string()],
+        total: 1,
+    }
+    .into();
+    assert_eq!(preview.first.as_deref(), Some("x"));
+    assert_eq!(preview.total, 1);
+}
+
+// `Page::<T>` and `Page<T>` name the same source type
+#[derive(Debug, DtoFrom)]
+#[dto(from = Page::<T>)]
+pub struct TurbofishDto<T> {
+    pub items: Vec<T>,
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = types::NamePage)]
+pub struct NamePageDto {
+    pub total: u64,
+}
+
+#[test]
+fn test_turbofish_and_alias_sources() {
+    let dto: TurbofishDto<u8> = Page {
+        items: vec![1, 2],
+        total: 2,
+    }
+    .into();
+    assert_eq!(dto.items, [1, 2]);
+
+    let names: NamePageDto = types::NamePage {
+        items: vec!["a".to_string()],
+        total: 1,
+    }
+    .into();
+    assert_eq!(names.total, 1);
+}
+
+// `T` is only named by the source, so the DTO itself stays non-generic.
+#[derive(Debug, DtoFrom)]
+#[dto(from = Page<T>, generics_from_source, bound = "T: Debug")]
+pub struct PageSummaryDto {
+    #[dto(transform_fn = types::item_count, from_source)]
+    pub count: usize,
+    pub total: u64,
+}
+
+#[test]
+fn test_generics_from_source() {
+    let summary: PageSummaryDto = Page {
+        items: vec!['a', 'b', 'c'],
+        total: 10,
+    }
+    .into();
+    assert_eq!(summary.count, 3);
+    assert_eq!(summary.total, 10);
+
+    let empty: PageSummaryDto = Page::<String> {
+        items: vec![],
+        total: 0,
+    }
+    .into();
+    assert_eq!(empty.count, 0);
+}
+
+// `where` is the same key as `bound`, and may repeat the keyword itself
+#[derive(Debug, DtoFrom)]
+#[dto(from = Page<T>, where = "where T: Clone + Default")]
+pub struct FirstOrDefaultDto<T> {
+    #[dto(transform_expr = |page| types::first_cloned(page).unwrap_or_default(), from_source)]
+    pub first: T,
+}
+
+#[test]
+fn test_where_adds_bounds() {
+    let dto: FirstOrDefaultDto<u8> = Page {
+        items: vec![],
+        total: 0,
+    }
+    .into();
+    assert_eq!(dto.first, 0);
+}
+
+// the `T: Clone` bound stays on the `From` impl; `map_from` / `from_vec` only need `Self: From<__S>`
+#[derive(Debug, DtoFrom)]
+#[dto(
+    from = Page<T>,
+    generics_from_source,
+    bound = "T: Clone",
+    inherent,
+    collection_helper
+)]
+pub struct PageStatsDto {
+    #[dto(transform_fn = types::item_count, from_source)]
+    pub count: usize,
+}
+
+#[test]
+fn test_generics_from_source_with_helpers() {
+    let stats = PageStatsDto::map_from(Page {
+        items: vec![1u8, 2],
+        total: 2,
+    });
+    assert_eq!(stats.count, 2);
+
+    let all = PageStatsDto::from_vec(vec![Page::<char> {
+        items: vec!['x'],
+        total: 1,
+    }]);
+    assert_eq!(all[0].count, 1);
+}
@@ -0,0 +1,89 @@
+//! Integration test compiling the generated code in a `#![no_std]` crate with `alloc`.
+//!
+//! The crate has no `std` in scope, so a `::std` path in the expansion fails to build here.
+//!
+//! Covers:
+//! - `DtoFrom`, `DtoTryFrom` and `DtoInto` impls using only `core` paths
+//! - `box` / `unbox`, `cow`, `from_str` and `collection_helper`, which reach `alloc` items
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use simple_dto_mapper_derive::{DtoFrom, DtoInto, DtoTryFrom};
+
+mod types {
+    use alloc::boxed::Box;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Device {
+        pub id: u32,
+        pub name: String,
+        pub port: String,
+        pub parent: Box<u32>,
+        pub tags: Vec<u8>,
+    }
+}
+
+#[derive(Debug, DtoFrom, DtoInto)]
+#[dto(from = types::Device, collection_helper)]
+pub struct DeviceDto {
+    #[dto(box)]
+    pub id: Box<u32>,
+
+    #[dto(cow)]
+    pub name: Cow<'static, str>,
+
+    #[dto(from_str)]
+    pub port: u16,
+
+    #[dto(unbox)]
+    pub parent: u32,
+
+    pub tags: Vec<u8>,
+}
+
+#[derive(Debug, DtoTryFrom)]
+#[dto(from = types::Device, error = core::num::TryFromIntError)]
+pub struct CompactDeviceDto {
+    #[dto(try_into)]
+    pub id: u8,
+
+    #[dto(skip)]
+    pub note: Option<String>,
+}
+
+#[test]
+fn test_no_std_expansion() {
+    let device = types::Device {
+        id: 7,
+        name: "uart".to_string(),
+        port: "8080".to_string(),
+        parent: Box::new(1),
+        tags: vec![1, 2],
+    };
+
+    let dto: DeviceDto = device.clone().into();
+    assert_eq!(*dto.id, 7);
+    assert_eq!(dto.name, "uart");
+    assert_eq!(dto.port, 8080);
+    assert_eq!(dto.parent, 1);
+    assert_eq!(dto.tags, [1, 2]);
+
+    let back: types::Device = dto.into();
+    assert_eq!(back, device);
+
+    let all = DeviceDto::from_vec(vec![device.clone()]);
+    assert_eq!(all.len(), 1);
+
+    let compact = CompactDeviceDto::try_from(device).unwrap();
+    assert_eq!(compact.id, 7);
+    assert!(compact.note.is_none());
+}
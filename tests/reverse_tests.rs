@@ -0,0 +1,346 @@
+//! Integration test for the reverse `#[derive(DtoInto)]` macro.
+//!
+//! Covers:
+//! - `impl From<Target> for Source` generated from the same `#[dto(from = ...)]`
+//! - `rename` swapping direction (DTO field -> renamed source field)
+//! - `reverse_transform_fn` for fields whose forward `transform_fn` is not invertible
+//! - `into` reused in the reverse direction
+//! - `skip` fields dropped, with `reverse_default` filling unmapped source fields
+//! - Round-tripping alongside `DtoFrom`
+//! - `box` / `unbox` swapping roles in the reverse direction
+//! - `with = module` calling `module::from_source` and `module::to_source`
+//! - `as = Type` casting back with `as _`
+//! - `arc` taking the value back out with `Arc::unwrap_or_clone`
+//! - Qualified trait-method paths (`<T as Trait>::f`) as `reverse_transform_fn`
+//! - `cell` / `refcell` taking the value back out with `into_inner`, or reading it under `by_ref`
+
+use simple_dto_mapper_derive::{DtoFrom, DtoInto};
+
+mod types {
+    #[derive(Debug, Clone, PartialEq, Eq, Default)]
+    pub enum SourceStatus {
+        #[default]
+        Active,
+        Inactive,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Default)]
+    pub struct Source {
+        pub id: String,
+        pub name: String,
+        pub cents: u64,
+        pub status: SourceStatus,
+        pub password: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum DtoStatus {
+        Active,
+        Inactive,
+    }
+
+    impl From<SourceStatus> for DtoStatus {
+        fn from(s: SourceStatus) -> Self {
+            match s {
+                SourceStatus::Active => DtoStatus::Active,
+                SourceStatus::Inactive => DtoStatus::Inactive,
+            }
+        }
+    }
+
+    impl From<DtoStatus> for SourceStatus {
+        fn from(s: DtoStatus) -> Self {
+            match s {
+                DtoStatus::Active => SourceStatus::Active,
+                DtoStatus::Inactive => SourceStatus::Inactive,
+            }
+        }
+    }
+
+    pub fn cents_to_units(c: u64) -> f64 {
+        c as f64 / 100.0
+    }
+
+    pub fn units_to_cents(u: f64) -> u64 {
+        (u * 100.0).round() as u64
+    }
+}
+
+use types::*;
+
+#[derive(Debug, Clone, DtoFrom, DtoInto)]
+#[dto(from = types::Source, reverse_default)]
+pub struct Dto {
+    pub id: String,
+
+    // rename swaps direction: Dto.display_name -> Source.name
+    #[dto(rename = "name")]
+    pub display_name: String,
+
+    #[dto(
+        rename = "cents",
+        transform_fn = types::cents_to_units,
+        reverse_transform_fn = types::units_to_cents
+    )]
+    pub amount: f64,
+
+    #[dto(into)]
+    pub status: types::DtoStatus,
+
+    // not part of the source; dropped on the way back
+    #[dto(skip)]
+    pub placeholder: Option<String>,
+}
+
+#[test]
+fn test_reverse_mapping() {
+    let dto = Dto {
+        id: "u1".into(),
+        display_name: "Alice".into(),
+        amount: 12.34,
+        status: DtoStatus::Inactive,
+        placeholder: Some("ignored".into()),
+    };
+
+    let src: Source = dto.into();
+
+    assert_eq!(src.id, "u1");
+    assert_eq!(src.name, "Alice");
+    assert_eq!(src.cents, 1234);
+    assert_eq!(src.status, SourceStatus::Inactive);
+    // not carried by the DTO -> filled by `reverse_default`
+    assert_eq!(src.password, "");
+}
+
+#[test]
+fn test_round_trip() {
+    let original = Source {
+        id: "u2".into(),
+        name: "Bob".into(),
+        cents: 500,
+        status: SourceStatus::Active,
+        password: String::new(),
+    };
+
+    let dto: Dto = original.clone().into();
+    let back: Source = dto.into();
+
+    assert_eq!(back, original);
+}
+
+mod boxed {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Leaf(pub u32);
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Node {
+        pub label: Leaf,
+        pub next: Box<Leaf>,
+    }
+}
+
+#[derive(Debug, DtoFrom, DtoInto)]
+#[dto(from = boxed::Node)]
+pub struct NodeDto {
+    #[dto(box)]
+    pub label: Box<boxed::Leaf>,
+
+    #[dto(unbox)]
+    pub next: boxed::Leaf,
+}
+
+#[test]
+fn test_box_round_trip() {
+    let node = boxed::Node {
+        label: boxed::Leaf(1),
+        next: Box::new(boxed::Leaf(2)),
+    };
+
+    let dto: NodeDto = node.clone().into();
+    assert_eq!(*dto.label, boxed::Leaf(1));
+    assert_eq!(dto.next, boxed::Leaf(2));
+
+    let back: boxed::Node = dto.into();
+    assert_eq!(back, node);
+}
+
+mod conv {
+    pub mod cents {
+        pub fn from_source(cents: u64) -> f64 {
+            cents as f64 / 100.0
+        }
+
+        pub fn to_source(units: f64) -> u64 {
+            (units * 100.0).round() as u64
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Invoice {
+        pub total: u64,
+    }
+}
+
+#[derive(Debug, DtoFrom, DtoInto)]
+#[dto(from = conv::Invoice)]
+pub struct InvoiceDto {
+    #[dto(with = conv::cents)]
+    pub total: f64,
+}
+
+#[test]
+fn test_with_module_round_trip() {
+    let invoice = conv::Invoice { total: 1999 };
+
+    let dto: InvoiceDto = invoice.clone().into();
+    assert_eq!(dto.total, 19.99);
+
+    let back: conv::Invoice = dto.into();
+    assert_eq!(back, invoice);
+}
+
+mod gauge {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Gauge {
+        pub level: i32,
+    }
+}
+
+#[derive(Debug, DtoFrom, DtoInto)]
+#[dto(from = gauge::Gauge)]
+pub struct GaugeDto {
+    #[dto(as = u8)]
+    pub level: u8,
+}
+
+#[test]
+fn test_cast_round_trip() {
+    let gauge = gauge::Gauge { level: 42 };
+
+    let dto: GaugeDto = gauge.clone().into();
+    assert_eq!(dto.level, 42u8);
+
+    let back: gauge::Gauge = dto.into();
+    assert_eq!(back, gauge);
+}
+
+mod shared {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Document {
+        pub body: String,
+    }
+}
+
+#[derive(Debug, DtoFrom, DtoInto)]
+#[dto(from = shared::Document)]
+pub struct DocumentDto {
+    #[dto(arc)]
+    pub body: std::sync::Arc<String>,
+}
+
+#[test]
+fn test_arc_round_trip() {
+    let doc = shared::Document {
+        body: "text".into(),
+    };
+
+    let dto: DocumentDto = doc.clone().into();
+    let extra = dto.body.clone();
+    assert_eq!(*extra, "text");
+
+    // still shared with `extra`, so the value is cloned out
+    let back: shared::Document = dto.into();
+    assert_eq!(back, doc);
+}
+
+mod tally {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Tally {
+        pub count: u32,
+        pub notes: Vec<String>,
+    }
+}
+
+#[derive(Debug, DtoFrom, DtoInto)]
+#[dto(from = tally::Tally)]
+pub struct TallyDto {
+    #[dto(cell)]
+    pub count: std::cell::Cell<u32>,
+
+    #[dto(refcell)]
+    pub notes: std::cell::RefCell<Vec<String>>,
+}
+
+#[derive(Debug, DtoFrom, DtoInto)]
+#[dto(from = tally::Tally, by_ref)]
+pub struct TallyViewDto {
+    #[dto(cell)]
+    pub count: std::cell::Cell<u32>,
+
+    #[dto(refcell)]
+    pub notes: std::cell::RefCell<Vec<String>>,
+}
+
+#[test]
+fn test_cell_round_trip() {
+    let tally = tally::Tally {
+        count: 1,
+        notes: vec!["first".into()],
+    };
+
+    let dto: TallyDto = tally.clone().into();
+    dto.count.set(2);
+    dto.notes.borrow_mut().push("second".into());
+    let back: tally::Tally = dto.into();
+    assert_eq!(back.count, 2);
+    assert_eq!(back.notes, ["first", "second"]);
+
+    let view: TallyViewDto = (&tally).into();
+    let copy: tally::Tally = (&view).into();
+    assert_eq!(copy, tally);
+}
+
+mod percent {
+    pub trait Percent {
+        fn from_ratio(ratio: f32) -> u8;
+        fn to_ratio(percent: u8) -> f32;
+    }
+
+    pub struct Scale;
+
+    impl Percent for Scale {
+        fn from_ratio(ratio: f32) -> u8 {
+            (ratio * 100.0).round() as u8
+        }
+
+        fn to_ratio(percent: u8) -> f32 {
+            f32::from(percent) / 100.0
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Job {
+        pub done: f32,
+    }
+}
+
+#[derive(Debug, DtoFrom, DtoInto)]
+#[dto(from = percent::Job)]
+pub struct JobDto {
+    #[dto(
+        transform_fn = <percent::Scale as percent::Percent>::from_ratio,
+        reverse_transform_fn = <percent::Scale as percent::Percent>::to_ratio
+    )]
+    pub done: u8,
+}
+
+#[test]
+fn test_qualified_path_round_trip() {
+    let job = percent::Job { done: 0.25 };
+
+    let dto: JobDto = job.clone().into();
+    assert_eq!(dto.done, 25);
+
+    let back: percent::Job = dto.into();
+    assert_eq!(back, job);
+}
@@ -0,0 +1,423 @@
+//! Integration test for the fallible `#[derive(DtoTryFrom)]` macro.
+//!
+//! Covers:
+//! - `impl TryFrom<Source>` with the struct-level `#[dto(error = Type)]`
+//! - `#[dto(try_into)]` propagating `TryFrom` failures with `?`
+//! - `#[dto(try_transform_fn = path)]` propagating `Result`-returning transforms
+//! - Direct, `rename`, `into`, `transform_fn`, and `skip` fields alongside fallible ones
+//! - `#[dto(collection_helper)]` generating `try_from_vec` and `try_map_all`
+//! - `#[derive(DtoFrom)]` with `#[dto(fallible)]`: `transform_fn` returning `Result`, `into` via `TryInto`
+//! - `try_into` / `try_transform_fn` accepted by `DtoFrom` under `fallible`
+//! - `map_err = path` converting each transform's own error type into the struct `error`
+//! - `#[dto(from_str)]` propagating the `FromStr` error with `?`
+//! - `#[dto(error(generate))]` emitting `<Target>ConversionError` with one variant per fallible field
+
+use simple_dto_mapper_derive::{DtoFrom, DtoTryFrom};
+
+mod types {
+    // ----- source side -----
+    #[derive(Debug, Clone)]
+    pub enum SourceStatus {
+        Active,
+        Inactive,
+    }
+
+    #[derive(Debug)]
+    pub struct Source {
+        pub id: String,
+        pub name: String,
+        pub level: i32,
+        pub age: String,
+        pub status: SourceStatus,
+    }
+
+    // ----- dto side -----
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum DtoStatus {
+        Active,
+        Inactive,
+    }
+
+    impl From<SourceStatus> for DtoStatus {
+        fn from(s: SourceStatus) -> Self {
+            match s {
+                SourceStatus::Active => DtoStatus::Active,
+                SourceStatus::Inactive => DtoStatus::Inactive,
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Rank {
+        Junior,
+        Senior,
+    }
+
+    impl TryFrom<i32> for Rank {
+        type Error = RankError;
+        fn try_from(v: i32) -> Result<Self, Self::Error> {
+            match v {
+                0 => Ok(Rank::Junior),
+                1 => Ok(Rank::Senior),
+                other => Err(RankError(other)),
+            }
+        }
+    }
+
+    // ----- errors -----
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct RankError(pub i32);
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum ConvError {
+        Rank(i32),
+        Age(String),
+    }
+
+    impl From<std::num::ParseIntError> for ConvError {
+        fn from(_: std::num::ParseIntError) -> Self {
+            ConvError::Age("not a number".into())
+        }
+    }
+
+    impl From<RankError> for ConvError {
+        fn from(e: RankError) -> Self {
+            ConvError::Rank(e.0)
+        }
+    }
+
+    // ----- helpers -----
+    pub fn parse_age(s: String) -> Result<u32, ConvError> {
+        s.parse().map_err(|_| ConvError::Age(s))
+    }
+
+    pub fn to_display_name(name: String) -> String {
+        name.to_uppercase()
+    }
+}
+
+use types::*;
+
+#[derive(Debug, DtoTryFrom)]
+#[dto(from = types::Source, error = types::ConvError)]
+pub struct Dto {
+    // direct 1:1 mapping
+    pub id: String,
+
+    // rename + infallible transform
+    #[dto(rename = "name", transform_fn = types::to_display_name)]
+    pub display_name: String,
+
+    // fallible conversion via TryFrom<i32> for Rank
+    #[dto(rename = "level", try_into)]
+    pub rank: types::Rank,
+
+    // fallible transform returning Result<u32, ConvError>
+    #[dto(try_transform_fn = types::parse_age)]
+    pub age: u32,
+
+    // infallible into
+    #[dto(into)]
+    pub status: types::DtoStatus,
+
+    // skip -> Default::default()
+    #[dto(skip)]
+    pub placeholder: Option<String>,
+}
+
+fn source(level: i32, age: &str) -> Source {
+    Source {
+        id: "u1".into(),
+        name: "Alice".into(),
+        level,
+        age: age.into(),
+        status: SourceStatus::Inactive,
+    }
+}
+
+#[test]
+fn test_try_from_success() {
+    let dto = Dto::try_from(source(1, "42")).expect("conversion should succeed");
+
+    assert_eq!(dto.id, "u1");
+    assert_eq!(dto.display_name, "ALICE");
+    assert_eq!(dto.rank, Rank::Senior);
+    assert_eq!(dto.age, 42);
+    assert_eq!(dto.status, DtoStatus::Inactive);
+    assert_eq!(dto.placeholder, None);
+}
+
+#[test]
+fn test_try_from_propagates_errors() {
+    let err = Dto::try_from(source(7, "42")).unwrap_err();
+    assert_eq!(err, ConvError::Rank(7));
+
+    let err = Dto::try_from(source(0, "forty-two")).unwrap_err();
+    assert_eq!(err, ConvError::Age("forty-two".into()));
+
+    let res: Result<Dto, ConvError> = source(0, "1").try_into();
+    assert!(res.is_ok());
+}
+
+#[derive(Debug, DtoTryFrom)]
+#[dto(from = types::Source, error = types::ConvError, collection_helper)]
+pub struct RankOnlyDto {
+    #[dto(rename = "level", try_into)]
+    pub rank: types::Rank,
+}
+
+#[test]
+fn test_try_from_vec_stops_at_first_error() {
+    let ranks = RankOnlyDto::try_from_vec(vec![source(0, "1"), source(1, "1")]).unwrap();
+    assert_eq!(ranks[1].rank, Rank::Senior);
+
+    let err = RankOnlyDto::try_from_vec(vec![source(0, "1"), source(5, "1")]).unwrap_err();
+    assert_eq!(err, ConvError::Rank(5));
+}
+
+#[test]
+fn test_try_map_all_accepts_any_iterable() {
+    let ranks = RankOnlyDto::try_map_all([source(1, "1"), source(0, "1")]).unwrap();
+    assert_eq!(ranks[0].rank, Rank::Senior);
+
+    let err = RankOnlyDto::try_map_all((4..7).map(|level| source(level, "1"))).unwrap_err();
+    assert_eq!(err, ConvError::Rank(4));
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = types::Source, fallible, error = types::ConvError)]
+pub struct FallibleFromDto {
+    pub id: String,
+
+    // `TryFrom<i32> for Rank` under `fallible`
+    #[dto(rename = "level", into)]
+    pub rank: types::Rank,
+
+    // `Result`-returning transform under `fallible`
+    #[dto(transform_fn = types::parse_age)]
+    pub age: u32,
+}
+
+#[test]
+fn test_fallible_dto_from() {
+    let dto = FallibleFromDto::try_from(source(0, "7")).unwrap();
+    assert_eq!(dto.id, "u1");
+    assert_eq!(dto.rank, Rank::Junior);
+    assert_eq!(dto.age, 7);
+
+    let err = FallibleFromDto::try_from(source(3, "7")).unwrap_err();
+    assert_eq!(err, ConvError::Rank(3));
+
+    let err = FallibleFromDto::try_from(source(0, "x")).unwrap_err();
+    assert_eq!(err, ConvError::Age("x".into()));
+}
+
+// The `DtoTryFrom` spellings work under `fallible` too, next to direct fields.
+#[derive(Debug, DtoFrom)]
+#[dto(from = types::Source, fallible, error = types::ConvError)]
+pub struct FallibleMixedDto {
+    pub id: String,
+
+    #[dto(rename = "level", try_into)]
+    pub rank: types::Rank,
+
+    #[dto(try_transform_fn = types::parse_age)]
+    pub age: u32,
+}
+
+#[test]
+fn test_fallible_dto_from_accepts_try_keys() {
+    let dto = FallibleMixedDto::try_from(source(1, "30")).unwrap();
+    assert_eq!(dto.id, "u1");
+    assert_eq!(dto.rank, Rank::Senior);
+    assert_eq!(dto.age, 30);
+
+    let err = FallibleMixedDto::try_from(source(9, "30")).unwrap_err();
+    assert_eq!(err, ConvError::Rank(9));
+}
+
+#[derive(Debug, DtoTryFrom)]
+#[dto(from = types::Source, error = types::ConvError)]
+pub struct ParsedAgeDto {
+    #[dto(from_str)]
+    pub age: u8,
+}
+
+#[test]
+fn test_from_str_propagates_parse_errors() {
+    assert_eq!(ParsedAgeDto::try_from(source(0, "42")).unwrap().age, 42);
+
+    let err = ParsedAgeDto::try_from(source(0, "old")).unwrap_err();
+    assert_eq!(err, ConvError::Age("not a number".into()));
+}
+
+#[derive(Debug, DtoTryFrom)]
+#[dto(from = types::Source, error(generate))]
+pub struct TaggedDto {
+    pub id: String,
+
+    #[dto(rename = "level", try_into, error = types::RankError)]
+    pub rank: types::Rank,
+
+    #[dto(try_transform_fn = types::parse_age, error = types::ConvError)]
+    pub age: u32,
+}
+
+#[test]
+fn test_generated_error_names_the_field() {
+    let dto = TaggedDto::try_from(source(1, "30")).unwrap();
+    assert_eq!(dto.rank, Rank::Senior);
+
+    match TaggedDto::try_from(source(9, "30")).unwrap_err() {
+        TaggedDtoConversionError::Rank(e) => assert_eq!(e, RankError(9)),
+        other => panic!("unexpected {other:?}"),
+    }
+    match TaggedDto::try_from(source(0, "old")).unwrap_err() {
+        TaggedDtoConversionError::Age(e) => assert_eq!(e, ConvError::Age("old".into())),
+        other => panic!("unexpected {other:?}"),
+    }
+}
+
+mod ranked {
+    pub struct Team {
+        pub levels: Vec<i32>,
+        pub lead: Option<i32>,
+    }
+}
+
+// `into` under `fallible` converts the elements: `Vec<i32>` -> `Vec<Rank>`, `Option<i32>` -> `Option<Rank>`
+#[derive(Debug, DtoFrom)]
+#[dto(from = ranked::Team, fallible, error = types::ConvError)]
+pub struct TeamDto {
+    #[dto(into)]
+    pub levels: Vec<types::Rank>,
+
+    #[dto(into)]
+    pub lead: Option<types::Rank>,
+}
+
+#[test]
+fn test_fallible_into_converts_wrapped_values() {
+    let dto = TeamDto::try_from(ranked::Team {
+        levels: vec![0, 1],
+        lead: Some(1),
+    })
+    .unwrap();
+    assert_eq!(dto.levels, [Rank::Junior, Rank::Senior]);
+    assert_eq!(dto.lead, Some(Rank::Senior));
+
+    let err = TeamDto::try_from(ranked::Team {
+        levels: vec![0],
+        lead: Some(4),
+    })
+    .unwrap_err();
+    assert_eq!(err, ConvError::Rank(4));
+}
+
+#[derive(Debug, PartialEq, DtoTryFrom)]
+#[dto(from = types::Source, error = types::ConvError, inherent)]
+pub struct RankedNameDto {
+    pub name: String,
+
+    #[dto(rename = "level", try_into)]
+    pub rank: types::Rank,
+}
+
+#[test]
+fn test_inherent_try_map_from() {
+    assert_eq!(
+        RankedNameDto::try_map_from(source(1, "1")),
+        RankedNameDto::try_from(source(1, "1"))
+    );
+    assert_eq!(
+        RankedNameDto::try_map_from(source(7, "1")).unwrap_err(),
+        ConvError::Rank(7)
+    );
+}
+
+mod upstream {
+    pub struct Parsed {
+        pub rank: Result<i32, super::types::ConvError>,
+    }
+}
+
+// under `DtoTryFrom` the source's `Err` is propagated with `?`
+#[derive(Debug, DtoTryFrom)]
+#[dto(from = upstream::Parsed, error = types::ConvError)]
+pub struct ParsedRankDto {
+    #[dto(unwrap_result)]
+    pub rank: i32,
+}
+
+#[test]
+fn test_unwrap_result_propagates_err() {
+    let ok = ParsedRankDto::try_from(upstream::Parsed { rank: Ok(3) }).unwrap();
+    assert_eq!(ok.rank, 3);
+
+    let err = ParsedRankDto::try_from(upstream::Parsed {
+        rank: Err(ConvError::Rank(-1)),
+    })
+    .unwrap_err();
+    assert_eq!(err, ConvError::Rank(-1));
+}
+
+mod loose {
+    use super::types::ConvError;
+
+    // transforms from another module, each with its own error type
+    pub fn parse_level(level: i32) -> Result<u8, String> {
+        u8::try_from(level).map_err(|_| format!("level {level} out of range"))
+    }
+
+    pub fn check_id(id: String) -> Result<String, usize> {
+        if id.is_empty() {
+            Err(0)
+        } else {
+            Ok(id)
+        }
+    }
+
+    pub fn level_error(message: String) -> ConvError {
+        ConvError::Age(message)
+    }
+
+    pub fn id_error(_: usize) -> ConvError {
+        ConvError::Rank(0)
+    }
+}
+
+#[derive(Debug, DtoTryFrom)]
+#[dto(from = types::Source, error = types::ConvError)]
+pub struct UnifiedErrorDto {
+    #[dto(rename = "level", try_transform_fn = loose::parse_level, map_err = loose::level_error)]
+    pub level: u8,
+}
+
+#[derive(Debug, DtoFrom)]
+#[dto(from = types::Source, fallible, error = types::ConvError)]
+pub struct FallibleUnifiedErrorDto {
+    #[dto(transform_fn = loose::check_id, map_err = loose::id_error)]
+    pub id: String,
+}
+
+#[test]
+fn test_map_err_unifies_transform_errors() {
+    assert_eq!(UnifiedErrorDto::try_from(source(3, "1")).unwrap().level, 3);
+    assert_eq!(
+        UnifiedErrorDto::try_from(source(-1, "1")).unwrap_err(),
+        ConvError::Age("level -1 out of range".into())
+    );
+
+    assert_eq!(
+        FallibleUnifiedErrorDto::try_from(source(0, "1"))
+            .unwrap()
+            .id,
+        "u1"
+    );
+    let mut empty = source(0, "1");
+    empty.id.clear();
+    assert_eq!(
+        FallibleUnifiedErrorDto::try_from(empty).unwrap_err(),
+        ConvError::Rank(0)
+    );
+}
@@ -0,0 +1,14 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+struct Source {
+    name: String,
+}
+
+#[derive(DtoFrom)]
+#[dto(from = Source)]
+struct Dto {
+    #[dto(into, args(10))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub id: u32,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    #[dto(assert_type = u64, into)]
+    id: u64,
+}
+
+fn main() {}
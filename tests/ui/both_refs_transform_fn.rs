@@ -0,0 +1,20 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub name: String,
+    }
+
+    pub fn shout(name: String) -> String {
+        name.to_uppercase()
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source, both_refs)]
+struct Dto {
+    #[dto(transform_fn = types::shout)]
+    name: String,
+}
+
+fn main() {}
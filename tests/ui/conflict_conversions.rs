@@ -0,0 +1,16 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub count: u32,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    #[dto(into, as = u64)]
+    count: u64,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub id: String,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    #[dto(rename = "id", default = String::new())]
+    name: String,
+}
+
+fn main() {}
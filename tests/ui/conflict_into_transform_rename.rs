@@ -0,0 +1,20 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub name: String,
+    }
+    pub fn upper(s: String) -> String {
+        s.to_uppercase()
+    }
+}
+
+// the conflict is reported on both keys whatever their order
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    #[dto(into, rename = "name", transform_fn = types::upper)]
+    display: String,
+}
+
+fn main() {}
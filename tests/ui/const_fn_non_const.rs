@@ -0,0 +1,22 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+struct Source {
+    id: u64,
+    name: String,
+}
+
+fn shout(s: String) -> String {
+    s.to_uppercase()
+}
+
+#[derive(DtoFrom)]
+#[dto(from = Source, const_fn)]
+struct Dto {
+    id: u64,
+    #[dto(transform_fn = shout)]
+    name: String,
+    #[dto(skip)]
+    count: u32,
+}
+
+fn main() {}
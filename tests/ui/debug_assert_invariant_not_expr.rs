@@ -0,0 +1,17 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub start: u32,
+        pub end: u32,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source, debug_assert_invariant = "self.start <=")]
+struct Dto {
+    start: u32,
+    end: u32,
+}
+
+fn main() {}
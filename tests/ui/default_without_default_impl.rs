@@ -0,0 +1,24 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub id: String,
+    }
+
+    pub struct NoDefault;
+}
+
+// a bare `default` is `skip`; each missing impl is reported on its field name
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    id: String,
+
+    #[dto(default)]
+    first: types::NoDefault,
+
+    #[dto(skip)]
+    second: types::NoDefault,
+}
+
+fn main() {}
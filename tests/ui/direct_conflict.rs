@@ -0,0 +1,17 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+struct Source {
+    id: u64,
+    name: String,
+}
+
+#[derive(DtoFrom)]
+#[dto(from = Source)]
+struct Dto {
+    #[dto(direct, into)]
+    id: u64,
+    #[dto(direct, rename = "name")]
+    label: String,
+}
+
+fn main() {}
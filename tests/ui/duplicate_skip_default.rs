@@ -0,0 +1,18 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub id: String,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    id: String,
+
+    #[dto(skip, default)]
+    note: Option<String>,
+}
+
+fn main() {}
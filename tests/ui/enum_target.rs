@@ -0,0 +1,17 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub enum Source {
+        Active,
+        Inactive,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+enum Dto {
+    Active,
+    Inactive,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+struct Source {
+    id: u64,
+}
+
+#[derive(DtoFrom)]
+#[dto(from = Source)]
+enum Dto {
+    Wrapped(u64),
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub id: String,
+    }
+
+    pub struct ConvError;
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source, error = types::ConvError)]
+struct Dto {
+    id: String,
+}
+
+fn main() {}
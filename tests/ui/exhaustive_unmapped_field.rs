@@ -0,0 +1,16 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub id: String,
+        pub password: String,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source, exhaustive)]
+struct Dto {
+    id: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+#[derive(DtoFrom)]
+#[dto(from = )]
+struct Dto {
+    id: String,
+}
+
+fn main() {}
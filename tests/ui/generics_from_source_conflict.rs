@@ -0,0 +1,13 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+struct Page<T> {
+    items: Vec<T>,
+}
+
+#[derive(DtoFrom)]
+#[dto(from = Page<T>, generics_from_source)]
+struct Dto<T> {
+    items: Vec<T>,
+}
+
+fn main() {}
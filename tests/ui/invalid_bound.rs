@@ -0,0 +1,15 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Page<T> {
+        pub items: Vec<T>,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Page<T>, bound = "T Clone")]
+struct PageDto<T> {
+    items: Vec<T>,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub id: u32,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source, rename_all = "kebab-case")]
+struct Dto {
+    id: u32,
+}
+
+fn main() {}
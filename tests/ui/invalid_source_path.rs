@@ -0,0 +1,19 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Address {
+        pub city: String,
+    }
+    pub struct Source {
+        pub address: Address,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    #[dto(source_path = "address.")]
+    city: String,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+struct Source {
+    email: String,
+}
+
+#[derive(DtoFrom)]
+#[dto(from = Source)]
+struct Dto {
+    #[dto(key = "email")]
+    email: String,
+}
+
+fn main() {}
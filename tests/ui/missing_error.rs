@@ -0,0 +1,16 @@
+use simple_dto_mapper_derive::DtoTryFrom;
+
+mod types {
+    pub struct Source {
+        pub level: i32,
+    }
+}
+
+#[derive(DtoTryFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    #[dto(try_into)]
+    level: u8,
+}
+
+fn main() {}
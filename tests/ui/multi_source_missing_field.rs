@@ -0,0 +1,21 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Account {
+        pub id: u64,
+        pub email: String,
+    }
+
+    pub struct Invite {
+        pub id: u64,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Account, from = types::Invite)]
+struct Dto {
+    id: u64,
+    email: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+struct Source {
+    id: u64,
+    name: String,
+}
+
+#[derive(DtoFrom)]
+#[dto(from = Source, only_annotated)]
+struct Dto {
+    #[dto(direct)]
+    id: u64,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct RawStatus;
+    pub struct Status;
+
+    pub struct Source {
+        pub raw_status: RawStatus,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    #[dto(rename = "raw_status", into)]
+    status: types::Status,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub abc: String,
+        pub a_b: String,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    #[dto(rename = "123abc")]
+    first: String,
+    #[dto(rename = "a-b")]
+    second: String,
+}
+
+fn main() {}
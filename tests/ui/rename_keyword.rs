@@ -0,0 +1,17 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub owner: String,
+    }
+}
+
+// most keywords are read as raw identifiers (`type` reads `source.r#type`), but `self` cannot be
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    #[dto(rename = "self")]
+    owner: String,
+}
+
+fn main() {}
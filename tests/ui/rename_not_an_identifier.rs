@@ -0,0 +1,17 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub full_name: String,
+    }
+}
+
+// a field name cannot contain a space, so there is no field this could read
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    #[dto(rename = "full name")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use simple_dto_mapper_derive::DtoInto;
+
+mod types {
+    pub struct Source {
+        pub name: String,
+    }
+    pub fn upper(s: String) -> String {
+        s.to_uppercase()
+    }
+}
+
+#[derive(DtoInto)]
+#[dto(from = types::Source)]
+struct Dto {
+    #[dto(transform_fn = types::upper)]
+    name: String,
+}
+
+fn main() {}
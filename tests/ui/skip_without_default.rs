@@ -0,0 +1,20 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub id: String,
+    }
+
+    pub struct NoDefault;
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    id: String,
+
+    #[dto(skip)]
+    extra: types::NoDefault,
+}
+
+fn main() {}
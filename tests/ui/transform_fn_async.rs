@@ -0,0 +1,20 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub name: String,
+    }
+
+    pub async fn normalize(name: String) -> String {
+        name.to_lowercase()
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    #[dto(transform_fn = types::normalize)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub name: String,
+    }
+
+    pub struct Mask {
+        pub width: usize,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+struct Dto {
+    #[dto(transform_fn = types::Mask)]
+    name: String,
+}
+
+fn main() {}
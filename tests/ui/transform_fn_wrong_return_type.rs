@@ -0,0 +1,18 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+struct Source {
+    name: String,
+}
+
+fn name_len(name: String) -> usize {
+    name.len()
+}
+
+#[derive(DtoFrom)]
+#[dto(from = Source)]
+struct Dto {
+    #[dto(transform_fn = name_len)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use simple_dto_mapper_derive::DtoFrom;
+
+mod types {
+    pub struct Source {
+        pub bits: u32,
+    }
+}
+
+#[derive(DtoFrom)]
+#[dto(from = types::Source)]
+union Dto {
+    bits: u32,
+    float: f32,
+}
+
+fn main() {}